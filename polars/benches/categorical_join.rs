@@ -0,0 +1,68 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polars::prelude::*;
+use polars_core::{enable_string_cache, reset_string_cache};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A frame with a `"key"` column of `n_rows` random `Utf8` keys out of `n_keys` distinct ones
+/// and a `"value"` column.
+fn key_frame(n_rows: usize, n_keys: u64, seed: u64) -> DataFrame {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let keys: Utf8Chunked = (0..n_rows)
+        .map(|_| Some(format!("key_{}", rng.gen_range(0..n_keys))))
+        .collect();
+    let values = UInt64Chunked::from_vec("value", (0..n_rows as u64).collect());
+    DataFrame::new(vec![
+        keys.into_series().with_name("key"),
+        values.into_series(),
+    ])
+    .unwrap()
+}
+
+fn to_categorical(df: &DataFrame) -> DataFrame {
+    let mut df = df.clone();
+    df.try_apply("key", |s| s.cast(&DataType::Categorical(None)))
+        .unwrap();
+    df
+}
+
+fn inner_join(left: &DataFrame, right: &DataFrame) -> DataFrame {
+    left.inner_join(right, ["key"], ["key"]).unwrap()
+}
+
+/// Categorical keys of different sources are re-encoded with a shared dictionary before they
+/// are joined on their codes. Codes of a global string cache are first mapped to indices into
+/// its strings, one hash map lookup per element. This compares those paths against joining on
+/// the strings and against categoricals that already share a string cache.
+fn bench_categorical_sources(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inner_join_categorical_sources");
+    let left = key_frame(1_000_000, 100_000, 0);
+    let right = key_frame(100_000, 100_000, 1);
+    group.bench_function("utf8", |b| b.iter(|| inner_join(&left, &right)));
+
+    reset_string_cache();
+    let (left_local, right_local) = (to_categorical(&left), to_categorical(&right));
+    group.bench_function("local_maps", |b| {
+        b.iter(|| inner_join(&left_local, &right_local))
+    });
+
+    enable_string_cache(true);
+    let (left_same, right_same) = (to_categorical(&left), to_categorical(&right));
+    group.bench_function("same_string_cache", |b| {
+        b.iter(|| inner_join(&left_same, &right_same))
+    });
+
+    // `reset_string_cache` starts a new cache, so `right` gets another source
+    let left_global = to_categorical(&left);
+    reset_string_cache();
+    enable_string_cache(true);
+    let right_global = to_categorical(&right);
+    group.bench_function("different_string_caches", |b| {
+        b.iter(|| inner_join(&left_global, &right_global))
+    });
+    reset_string_cache();
+    group.finish();
+}
+
+criterion_group!(benches, bench_categorical_sources);
+criterion_main!(benches);
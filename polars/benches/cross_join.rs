@@ -0,0 +1,33 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use polars::prelude::*;
+
+fn frame(n_rows: usize, suffix: &str) -> DataFrame {
+    df![
+        &format!("int_{suffix}") => (0..n_rows as i64).collect::<Vec<_>>(),
+        &format!("str_{suffix}") => (0..n_rows).map(|i| format!("value_{i}")).collect::<Vec<_>>(),
+    ]
+    .unwrap()
+}
+
+/// An unsliced cross join repeats and tiles the columns, a sliced cross join takes the rows by
+/// an index of all output rows. A slice that covers all rows gives the same output, so this
+/// compares both paths.
+fn bench_cross_join(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cross_join");
+    for (n_left, n_right) in [(1_000, 100), (100, 1_000), (10_000, 100)] {
+        let left = frame(n_left, "left");
+        let right = frame(n_right, "right");
+        let total = n_left * n_right;
+        let id = format!("{n_left}x{n_right}");
+        group.bench_function(BenchmarkId::new("tile", &id), |b| {
+            b.iter(|| left.cross_join(&right, None, None).unwrap())
+        });
+        group.bench_function(BenchmarkId::new("take_idx", &id), |b| {
+            b.iter(|| left.cross_join(&right, None, Some((0, total))).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_cross_join);
+criterion_main!(benches);
@@ -0,0 +1,122 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use polars::prelude::*;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, split_df};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// A frame with a `"key"` column of `n_rows` random keys in `0..n_keys` and a `"value"` column.
+/// If `null_every` is given, every `null_every`th key is null.
+fn key_frame(n_rows: usize, n_keys: u64, null_every: Option<usize>, seed: u64) -> DataFrame {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let keys: UInt64Chunked = (0..n_rows)
+        .map(|i| match null_every {
+            Some(every) if i % every == 0 => None,
+            _ => Some(rng.gen_range(0..n_keys)),
+        })
+        .collect();
+    let values = UInt64Chunked::from_vec("value", (0..n_rows as u64).collect());
+    DataFrame::new(vec![
+        keys.into_series().with_name("key"),
+        values.into_series(),
+    ])
+    .unwrap()
+}
+
+/// The same frame, split into `n_chunks` chunks as if it was built incrementally.
+fn chunked(df: &DataFrame, n_chunks: usize) -> DataFrame {
+    let mut df = df.clone();
+    let out = accumulate_dataframes_vertical_unchecked(split_df(&mut df, n_chunks).unwrap());
+    assert_eq!(out.n_chunks(), n_chunks);
+    out
+}
+
+fn inner_join(left: &DataFrame, right: &DataFrame) -> DataFrame {
+    left.inner_join(right, ["key"], ["key"]).unwrap()
+}
+
+/// Keys with nulls are rechunked once before they are hashed. Keys that are a single chunk
+/// must not get slower by that.
+fn bench_key_chunks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inner_join_key_chunks");
+    for (label, null_every) in [("no_nulls", None), ("nulls", Some(10))] {
+        let left = key_frame(1_000_000, 100_000, null_every, 0);
+        let right = key_frame(100_000, 100_000, null_every, 1);
+        let left_chunked = chunked(&left, 64);
+        group.bench_with_input(BenchmarkId::new(label, "1_chunk"), &left, |b, left| {
+            b.iter(|| inner_join(left, &right))
+        });
+        group.bench_with_input(
+            BenchmarkId::new(label, "64_chunks"),
+            &left_chunked,
+            |b, left| b.iter(|| inner_join(left, &right)),
+        );
+    }
+    group.finish();
+}
+
+/// The build relations up to `POLARS_JOIN_NESTED_LOOP_MAX_LEN` rows are joined with a nested
+/// loop instead of a hash table. This compares both paths to find the cross-over point.
+fn bench_small_build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inner_join_small_build");
+    for build_len in [8, 16, 32, 64, 128, 256] {
+        let probe = key_frame(1_000_000, 2 * build_len as u64, None, 0);
+        let build = key_frame(build_len, 2 * build_len as u64, None, 1);
+        for (label, max_len) in [("hash", 0), ("nested_loop", usize::MAX)] {
+            std::env::set_var("POLARS_JOIN_NESTED_LOOP_MAX_LEN", max_len.to_string());
+            group.bench_with_input(BenchmarkId::new(label, build_len), &build, |b, build| {
+                b.iter(|| inner_join(&probe, build))
+            });
+        }
+    }
+    std::env::remove_var("POLARS_JOIN_NESTED_LOOP_MAX_LEN");
+    group.finish();
+}
+
+/// A key without nulls is hashed or probed as plain values, while only the nulls of the other
+/// key are skipped. This compares that against joining two keys that both have nulls.
+fn bench_dense_sparse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inner_join_dense_left_sparse_right");
+    let dense = key_frame(1_000_000, 100_000, None, 0);
+    let sparse = key_frame(1_000_000, 100_000, Some(10), 0);
+    let right = key_frame(100_000, 100_000, Some(10), 1);
+    group.bench_function("dense_left", |b| b.iter(|| inner_join(&dense, &right)));
+    group.bench_function("sparse_left", |b| b.iter(|| inner_join(&sparse, &right)));
+    group.finish();
+}
+
+/// `key_frame` with `n_columns` extra value columns, named `"col_0"`, `"col_1"`, etc.
+fn wide_frame(n_rows: usize, n_columns: usize, seed: u64) -> DataFrame {
+    let mut df = key_frame(n_rows, n_rows as u64, None, seed);
+    let values = df.column("value").unwrap().clone();
+    let columns = (0..n_columns)
+        .map(|i| values.clone().with_name(&format!("col_{i}")))
+        .collect::<Vec<_>>();
+    df.hstack_mut(&columns).unwrap();
+    df
+}
+
+/// Small, wide frames that are joined over and over, e.g. in a loop over batches. Finishing
+/// such a join is dominated by the per column work: the colliding names get a suffix and the
+/// right columns are moved next to the left ones.
+fn bench_wide_frames(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inner_join_wide_frames");
+    for n_columns in [10, 100, 1_000] {
+        let left = wide_frame(1_000, n_columns, 0);
+        let right = wide_frame(1_000, n_columns, 1);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(n_columns),
+            &right,
+            |b, right| b.iter(|| inner_join(&left, right)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_key_chunks,
+    bench_small_build,
+    bench_dense_sparse,
+    bench_wide_frames
+);
+criterion_main!(benches);
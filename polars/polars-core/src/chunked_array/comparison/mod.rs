@@ -861,14 +861,60 @@ impl Not for BooleanChunked {
 }
 
 impl BooleanChunked {
-    /// Check if all values are `true`
+    /// Check if all values are `true`.
+    ///
+    /// Null values are ignored, so an empty or all-null array returns `true`.
+    /// Use [`BooleanChunked::all_kleene`] to let nulls count.
     pub fn all(&self) -> bool {
-        self.downcast_iter().all(compute::boolean::all)
+        self.downcast_iter().all(|arr| {
+            if arr.null_count() == 0 {
+                arr.values().unset_bits() == 0
+            } else {
+                arr.into_iter().all(|v| v != Some(false))
+            }
+        })
     }
 
-    /// Check if any value is `true`
+    /// Check if any value is `true`.
+    ///
+    /// Null values are ignored, so an empty or all-null array returns `false`.
+    /// Use [`BooleanChunked::any_kleene`] to let nulls count.
     pub fn any(&self) -> bool {
-        self.downcast_iter().any(compute::boolean::any)
+        self.downcast_iter().any(|arr| {
+            if arr.null_count() == 0 {
+                arr.values().unset_bits() != arr.len()
+            } else {
+                arr.into_iter().any(|v| v == Some(true))
+            }
+        })
+    }
+
+    /// Check if all values are `true` using [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic).
+    ///
+    /// Returns `Some(false)` if any value is `false`, otherwise `None` if a null is present
+    /// and `Some(true)` if not.
+    pub fn all_kleene(&self) -> Option<bool> {
+        if !self.all() {
+            Some(false)
+        } else if self.null_count() > 0 {
+            None
+        } else {
+            Some(true)
+        }
+    }
+
+    /// Check if any value is `true` using [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic).
+    ///
+    /// Returns `Some(true)` if any value is `true`, otherwise `None` if a null is present
+    /// and `Some(false)` if not.
+    pub fn any_kleene(&self) -> Option<bool> {
+        if self.any() {
+            Some(true)
+        } else if self.null_count() > 0 {
+            None
+        } else {
+            Some(false)
+        }
     }
 }
 
@@ -1204,6 +1250,48 @@ mod test {
         assert_eq!(Vec::from(&c), &[Some(true), Some(false), None])
     }
 
+    #[test]
+    fn test_all_any() {
+        let mixed = BooleanChunked::new("", &[Some(true), None, Some(false)]);
+        assert!(!mixed.all());
+        assert!(mixed.any());
+        assert_eq!(mixed.all_kleene(), Some(false));
+        assert_eq!(mixed.any_kleene(), Some(true));
+
+        let true_and_null = BooleanChunked::new("", &[Some(true), None, Some(true)]);
+        assert!(true_and_null.all());
+        assert!(true_and_null.any());
+        assert_eq!(true_and_null.all_kleene(), None);
+        assert_eq!(true_and_null.any_kleene(), Some(true));
+
+        let false_and_null = BooleanChunked::new("", &[None, Some(false)]);
+        assert!(!false_and_null.all());
+        assert!(!false_and_null.any());
+        assert_eq!(false_and_null.all_kleene(), Some(false));
+        assert_eq!(false_and_null.any_kleene(), None);
+
+        let all_null = BooleanChunked::full_null("", 2);
+        assert!(all_null.all());
+        assert!(!all_null.any());
+        assert_eq!(all_null.all_kleene(), None);
+        assert_eq!(all_null.any_kleene(), None);
+
+        let empty = BooleanChunked::from_slice("", &[]);
+        assert!(empty.all());
+        assert!(!empty.any());
+        assert_eq!(empty.all_kleene(), Some(true));
+        assert_eq!(empty.any_kleene(), Some(false));
+
+        // multiple chunks
+        let mut ca = BooleanChunked::from_slice("", &[true, true]);
+        ca.append(&true_and_null);
+        assert!(ca.all());
+        assert_eq!(ca.all_kleene(), None);
+        ca.append(&BooleanChunked::from_slice("", &[false]));
+        assert!(!ca.all());
+        assert_eq!(ca.all_kleene(), Some(false));
+    }
+
     #[test]
     fn test_broadcasting_bools() {
         let a = BooleanChunked::from_slice("", &[true, false, true]);
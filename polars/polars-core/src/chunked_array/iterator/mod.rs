@@ -1,6 +1,7 @@
 use std::convert::TryFrom;
 
 use arrow::array::*;
+use arrow::Either;
 
 use crate::prelude::*;
 #[cfg(feature = "dtype-struct")]
@@ -208,6 +209,24 @@ impl Utf8Chunked {
                 .trust_my_length(self.len())
         }
     }
+
+    /// Iterate over the values of this array by reference. Null values are yielded as `None`.
+    ///
+    /// This is the same iterator as the one of `(&Utf8Chunked).into_iter()`.
+    pub fn iter(&self) -> Box<dyn PolarsIterator<Item = Option<&str>> + '_> {
+        self.into_iter()
+    }
+
+    /// Iterate over the non-null values of this array, skipping the null values.
+    ///
+    /// If the array has no null values, the validity isn't checked for every value.
+    pub fn iter_no_null(&self) -> impl Iterator<Item = &str> + '_ {
+        if self.null_count() == 0 {
+            Either::Left(self.into_no_null_iter())
+        } else {
+            Either::Right(self.into_iter().flatten())
+        }
+    }
 }
 
 impl<'a> IntoIterator for &'a BinaryChunked {
@@ -537,6 +556,20 @@ mod test {
         )
     }
 
+    #[test]
+    fn utf8_iter() {
+        let mut a = Utf8Chunked::new("a", &[Some("a"), None, Some("c")]);
+        a.append(&Utf8Chunked::new("a", &[None, Some("e")]));
+
+        let v = a.iter().collect::<Vec<_>>();
+        assert_eq!(v, a.into_iter().collect::<Vec<_>>());
+        assert_eq!(v, &[Some("a"), None, Some("c"), None, Some("e")]);
+        assert_eq!(a.iter_no_null().collect::<Vec<_>>(), &["a", "c", "e"]);
+
+        let a = Utf8Chunked::from_slice("a", &["x", "y"]);
+        assert_eq!(a.iter_no_null().collect::<Vec<_>>(), &["x", "y"]);
+    }
+
     /// Generate test for `IntoIterator` trait for chunked arrays with just one chunk and no null values.
     /// The expected return value of the iterator generated by `IntoIterator` trait is `Option<T>`, where
     /// `T` is the chunked array type.
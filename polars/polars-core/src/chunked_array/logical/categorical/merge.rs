@@ -79,10 +79,102 @@ pub(crate) fn merge_categorical_map(
     }
 }
 
+/// Get the codes of `ca` as indices into the string values of its [`RevMapping`].
+fn local_codes(ca: &CategoricalChunked) -> (UInt32Chunked, &Utf8Array<i64>) {
+    match &**ca.get_rev_map() {
+        RevMapping::Local(arr) => (ca.logical().clone(), arr),
+        RevMapping::Global(map, arr, _) => {
+            let codes = ca
+                .logical()
+                .apply_on_opt(|opt_code| opt_code.map(|code| map[&code]));
+            (codes, arr)
+        }
+    }
+}
+
 impl CategoricalChunked {
     pub(crate) fn merge_categorical_map(&self, other: &Self) -> PolarsResult<Arc<RevMapping>> {
         merge_categorical_map(self.get_rev_map(), other.get_rev_map())
     }
+
+    /// Re-encode `other` into the categories of `self`, so that equal strings get equal codes.
+    ///
+    /// `self` is returned untouched where possible:
+    /// * under the global string cache that is still active, the categories of `other` are
+    ///   added to that cache.
+    /// * with a local [`RevMapping`], the categories that only occur in `other` are appended
+    ///   to the ones of `self`, so the codes of `self` stay the same.
+    ///
+    /// Only if `self` was created under a global string cache that has since been reset, both
+    /// are re-encoded with a shared local [`RevMapping`].
+    pub fn unify_rev_maps(&self, other: &Self) -> (Self, Self) {
+        if let RevMapping::Global(_, _, id) = &**self.get_rev_map() {
+            let mut cache = crate::STRING_CACHE.lock_map();
+            if cache.uuid == *id {
+                let (right_codes, right_values) = local_codes(other);
+                let right_to_global = right_values
+                    .values_iter()
+                    .map(|v| cache.insert(v))
+                    .collect::<Vec<_>>();
+                drop(cache);
+                let right_codes = right_codes
+                    .apply_on_opt(|opt_code| opt_code.map(|code| right_to_global[code as usize]));
+                let map = right_to_global
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, global)| (*global, idx as u32))
+                    .collect::<PlHashMap<_, _>>();
+                let rev_map = Arc::new(RevMapping::Global(map, right_values.clone(), *id));
+                // safety: all codes are in the global cache and in the rev map
+                let right = unsafe {
+                    CategoricalChunked::from_cats_and_rev_map_unchecked(right_codes, rev_map)
+                };
+                return (self.clone(), right);
+            }
+        }
+
+        let (left_codes, left_values) = local_codes(self);
+        let (right_codes, right_values) = local_codes(other);
+
+        let mut values = left_values.values_iter().collect::<Vec<_>>();
+        let mut positions = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (*v, i as u32))
+            .collect::<PlHashMap<_, _>>();
+        let right_to_merged = right_values
+            .values_iter()
+            .map(|v| {
+                *positions.entry(v).or_insert_with(|| {
+                    values.push(v);
+                    (values.len() - 1) as u32
+                })
+            })
+            .collect::<Vec<_>>();
+        let right_codes = right_codes
+            .apply_on_opt(|opt_code| opt_code.map(|code| right_to_merged[code as usize]));
+
+        let left_is_local = matches!(&**self.get_rev_map(), RevMapping::Local(_));
+        let rev_map = if left_is_local && values.len() == left_values.len() {
+            // all categories of `other` occur in `self`
+            self.get_rev_map().clone()
+        } else {
+            Arc::new(RevMapping::Local(Utf8Array::<i64>::from_slice(values)))
+        };
+        // safety: all codes are indices into the merged values, of which the ones of `self`
+        // are a prefix
+        unsafe {
+            let left = if Arc::ptr_eq(&rev_map, self.get_rev_map()) {
+                self.clone()
+            } else {
+                CategoricalChunked::from_cats_and_rev_map_unchecked(left_codes, rev_map.clone())
+            };
+            (
+                left,
+                CategoricalChunked::from_cats_and_rev_map_unchecked(right_codes, rev_map),
+            )
+        }
+    }
 }
 
 #[cfg(test)]
@@ -39,7 +39,7 @@ impl LogicalType for DateChunked {
                     TimeUnit::Microseconds => US_IN_DAY,
                     TimeUnit::Milliseconds => MS_IN_DAY,
                 };
-                Ok((casted.deref() * conversion)
+                Ok(upscale_time_values(casted.deref(), conversion, dtype)?
                     .into_datetime(*tu, tz.clone())
                     .into_series())
             }
@@ -32,12 +32,12 @@ impl LogicalType for DatetimeChunked {
         use DataType::*;
         match (self.dtype(), dtype) {
             (Datetime(TimeUnit::Milliseconds, _), Datetime(TimeUnit::Nanoseconds, tz)) => {
-                Ok((self.0.as_ref() * 1_000_000i64)
+                Ok(upscale_time_values(&self.0, 1_000_000i64, dtype)?
                     .into_datetime(TimeUnit::Nanoseconds, tz.clone())
                     .into_series())
             }
             (Datetime(TimeUnit::Milliseconds, _), Datetime(TimeUnit::Microseconds, tz)) => {
-                Ok((self.0.as_ref() * 1_000i64)
+                Ok(upscale_time_values(&self.0, 1_000i64, dtype)?
                     .into_datetime(TimeUnit::Microseconds, tz.clone())
                     .into_series())
             }
@@ -57,7 +57,7 @@ impl LogicalType for DatetimeChunked {
                     .into_series())
             }
             (Datetime(TimeUnit::Microseconds, _), Datetime(TimeUnit::Nanoseconds, tz)) => {
-                Ok((self.0.as_ref() * 1_000i64)
+                Ok(upscale_time_values(&self.0, 1_000i64, dtype)?
                     .into_datetime(TimeUnit::Nanoseconds, tz.clone())
                     .into_series())
             }
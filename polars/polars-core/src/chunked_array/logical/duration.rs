@@ -31,12 +31,12 @@ impl LogicalType for DurationChunked {
         use DataType::*;
         match (self.dtype(), dtype) {
             (Duration(TimeUnit::Milliseconds), Duration(TimeUnit::Nanoseconds)) => {
-                Ok((self.0.as_ref() * 1_000_000i64)
+                Ok(upscale_time_values(&self.0, 1_000_000i64, dtype)?
                     .into_duration(TimeUnit::Nanoseconds)
                     .into_series())
             }
             (Duration(TimeUnit::Milliseconds), Duration(TimeUnit::Microseconds)) => {
-                Ok((self.0.as_ref() * 1_000i64)
+                Ok(upscale_time_values(&self.0, 1_000i64, dtype)?
                     .into_duration(TimeUnit::Microseconds)
                     .into_series())
             }
@@ -46,7 +46,7 @@ impl LogicalType for DurationChunked {
                     .into_series())
             }
             (Duration(TimeUnit::Microseconds), Duration(TimeUnit::Nanoseconds)) => {
-                Ok((self.0.as_ref() * 1_000i64)
+                Ok(upscale_time_values(&self.0, 1_000i64, dtype)?
                     .into_duration(TimeUnit::Nanoseconds)
                     .into_series())
             }
@@ -96,3 +96,25 @@ where
         Field::new(name, LogicalType::dtype(self).clone())
     }
 }
+
+/// Multiply the physical values of a temporal array by `factor` when converting to a finer time
+/// unit, raising an error instead of silently overflowing.
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+pub(crate) fn upscale_time_values(
+    ca: &Int64Chunked,
+    factor: i64,
+    dtype: &DataType,
+) -> PolarsResult<Int64Chunked> {
+    let in_bounds = match (ca.min(), ca.max()) {
+        (Some(min), Some(max)) => {
+            min.checked_mul(factor).is_some() && max.checked_mul(factor).is_some()
+        }
+        _ => true,
+    };
+    polars_ensure!(
+        in_bounds,
+        ComputeError: "cannot cast '{}' to {}: the values overflow the 64-bit representation",
+        ca.name(), dtype
+    );
+    Ok(ca * factor)
+}
@@ -165,6 +165,8 @@ where
 
     fn mean(&self) -> Option<f64> {
         match self.dtype() {
+            // the mean of no values is undefined, not `0 / 0`
+            DataType::Float64 if self.null_count() == self.len() => None,
             DataType::Float64 => {
                 let len = (self.len() - self.null_count()) as f64;
                 self.sum().map(|v| v.to_f64().unwrap() / len)
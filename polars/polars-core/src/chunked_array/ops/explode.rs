@@ -348,6 +348,33 @@ pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> Vec<IdxSiz
     idx
 }
 
+impl ListChunked {
+    /// Explode the lists and get the index of the row that every value came from, e.g. to
+    /// expand the other columns of a [`DataFrame`] with a take.
+    ///
+    /// An empty or null list produces a single null value if `keep_empty` is set and no value
+    /// otherwise.
+    pub fn explode_with_row_idx(&self, keep_empty: bool) -> PolarsResult<(Series, Vec<IdxSize>)> {
+        let (s, offsets) = self.explode_and_offsets()?;
+        let offsets = offsets.as_slice();
+        let row_idx = offsets_to_indexes(offsets, s.len());
+        if keep_empty {
+            return Ok((s, row_idx));
+        }
+        // an empty row is exploded to a single null, that we remove here
+        let is_empty = |row: IdxSize| {
+            let row = row as usize;
+            offsets[row] == offsets[row + 1]
+        };
+        let mask = row_idx
+            .iter()
+            .map(|&row| !is_empty(row))
+            .collect::<BooleanChunked>();
+        let row_idx = row_idx.into_iter().filter(|&row| !is_empty(row)).collect();
+        Ok((s.filter(&mask)?, row_idx))
+    }
+}
+
 impl ChunkExplode for ListChunked {
     fn explode_and_offsets(&self) -> PolarsResult<(Series, OffsetsBuffer<i64>)> {
         // A list array's memory layout is actually already 'exploded', so we can just take the values array
@@ -594,6 +621,28 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_explode_with_row_idx() -> PolarsResult<()> {
+        let mut builder = get_list_builder(&DataType::Int32, 5, 5, "a")?;
+        builder.append_series(&Series::new("", &[1i32, 2]));
+        builder.append_series(&Int32Chunked::from_slice("", &[]).into_series());
+        builder.append_null();
+        builder.append_series(&Series::new("", &[3i32]));
+        let ca = builder.finish();
+
+        let (exploded, row_idx) = ca.explode_with_row_idx(true)?;
+        assert_eq!(
+            Vec::from(exploded.i32()?),
+            &[Some(1), Some(2), None, None, Some(3)]
+        );
+        assert_eq!(row_idx, &[0, 0, 1, 2, 3]);
+
+        let (exploded, row_idx) = ca.explode_with_row_idx(false)?;
+        assert_eq!(Vec::from(exploded.i32()?), &[Some(1), Some(2), Some(3)]);
+        assert_eq!(row_idx, &[0, 0, 3]);
+        Ok(())
+    }
+
     #[test]
     fn test_explode_empty_list_slot() -> PolarsResult<()> {
         // primitive
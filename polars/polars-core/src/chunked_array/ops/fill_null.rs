@@ -13,6 +13,20 @@ fn err_fill_null() -> PolarsError {
     polars_err!(ComputeError: "could not determine the fill value")
 }
 
+/// Whether [`Series::fill_null`] can apply `strategy` to a [`Series`] of `dtype`.
+pub(crate) fn fill_null_strategy_supported(dtype: &DataType, strategy: FillNullStrategy) -> bool {
+    use DataType::*;
+    let is_directional = matches!(
+        strategy,
+        FillNullStrategy::Forward(_) | FillNullStrategy::Backward(_)
+    );
+    match dtype.to_physical() {
+        Boolean => !matches!(strategy, FillNullStrategy::Mean),
+        Utf8 | Binary | List(_) => is_directional,
+        dt => dt.is_numeric(),
+    }
+}
+
 impl Series {
     /// Replace None values with one of the following strategies:
     /// * Forward fill (replace None with the previous value)
@@ -23,7 +23,7 @@ mod cum_agg;
 pub(crate) mod downcast;
 pub(crate) mod explode;
 mod extend;
-mod fill_null;
+pub(crate) mod fill_null;
 mod filter;
 pub mod full;
 #[cfg(feature = "interpolate")]
@@ -460,6 +460,8 @@ pub trait ChunkUnique<T: PolarsDataType> {
     }
 
     /// The most occurring value(s). Can return multiple Values
+    ///
+    /// Tied values are returned in order of their first occurrence. Null counts as a value.
     #[cfg(feature = "mode")]
     fn mode(&self) -> PolarsResult<ChunkedArray<T>> {
         polars_bail!(opq = mode, T::get_dtype());
@@ -106,12 +106,14 @@ where
 
     // collect until we don't take with trusted len anymore
     // TODO! take directly from iter, but first remove standard trusted-length collect.
-    let idx = groups
+    let mut idx = groups
         .iter()
         .rev()
         .take_while(|v| v.1.len() == max_occur)
         .map(|v| v.0)
         .collect::<Vec<_>>();
+    // return tied modes in order of first occurrence
+    idx.sort_unstable();
     // Safety:
     // group indices are in bounds
     unsafe { ca.take_unchecked(idx.into_iter().map(|i| i as usize).into()) }
@@ -376,4 +378,21 @@ mod test {
         let result3 = Vec::from(&ca3.mode().unwrap());
         assert_eq!(result3, &[]);
     }
+
+    #[test]
+    #[cfg(feature = "mode")]
+    fn mode_utf8() {
+        let ca = Utf8Chunked::new("a", &[Some("x"), Some("y"), None, Some("y"), Some("z")]);
+        assert_eq!(Vec::from(&ca.mode().unwrap()), &[Some("y")]);
+
+        // ties are returned in order of first occurrence, null is a candidate
+        let ca = Utf8Chunked::new(
+            "a",
+            &[Some("y"), None, Some("x"), Some("x"), None, Some("y")],
+        );
+        assert_eq!(
+            Vec::from(&ca.mode().unwrap()),
+            &[Some("y"), None, Some("x")]
+        );
+    }
 }
@@ -44,9 +44,11 @@ fn get_random_seed() -> u64 {
 pub(crate) fn rank(s: &Series, method: RankMethod, descending: bool, seed: Option<u64>) -> Series {
     match s.len() {
         1 => {
+            // nulls rank as null
+            let rank = s.null_count() == 0;
             return match method {
-                Average => Series::new(s.name(), &[1.0f32]),
-                _ => Series::new(s.name(), &[1 as IdxSize]),
+                Average => Series::new(s.name(), &[rank.then_some(1.0f32)]),
+                _ => Series::new(s.name(), &[rank.then_some(1 as IdxSize)]),
             };
         }
         0 => {
@@ -437,6 +439,33 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_rank_nulls() -> PolarsResult<()> {
+        let s = Series::new("", &[Some(3), None, Some(1), Some(3)]);
+        for method in [
+            RankMethod::Min,
+            RankMethod::Max,
+            RankMethod::Dense,
+            RankMethod::Ordinal,
+        ] {
+            let out = rank(&s, method, false, None);
+            assert_eq!(out.null_count(), 1);
+            assert_eq!(out.get(1)?, AnyValue::Null);
+        }
+        let out = rank(&s, RankMethod::Average, false, None);
+        assert_eq!(
+            Vec::from(out.f32()?),
+            &[Some(2.5f32), None, Some(1.0), Some(2.5)]
+        );
+
+        let s = Series::new("", &[None::<i32>]);
+        let out = rank(&s, RankMethod::Min, false, None);
+        assert_eq!(Vec::from(out.idx()?), &[None]);
+        let out = rank(&s, RankMethod::Average, false, None);
+        assert_eq!(Vec::from(out.f32()?), &[None]);
+        Ok(())
+    }
+
     #[test]
     fn test_rank_empty() {
         let s = UInt32Chunked::from_slice("", &[]).into_series();
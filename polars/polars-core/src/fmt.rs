@@ -382,13 +382,61 @@ fn fmt_df_shape((shape0, shape1): &(usize, usize)) -> String {
     format!("({}, {})", fmt_uint(shape0), fmt_uint(shape1))
 }
 
+impl DataFrame {
+    /// Format this [`DataFrame`] as a table showing at most `max_rows` rows and `max_cols`
+    /// columns.
+    ///
+    /// Different from the [`Display`] implementation, the limits are not read from the
+    /// `POLARS_FMT_MAX_ROWS` and `POLARS_FMT_MAX_COLS` environment variables. Elided rows and
+    /// columns are shown as a `…` row or column between the head and the tail.
+    pub fn fmt_with_limits(&self, max_rows: usize, max_cols: usize) -> String {
+        DataFrameFmt {
+            df: self,
+            max_n_rows: max_rows,
+            max_n_cols: max_cols,
+        }
+        .to_string()
+    }
+}
+
+struct DataFrameFmt<'a> {
+    df: &'a DataFrame,
+    max_n_rows: usize,
+    max_n_cols: usize,
+}
+
 impl Display for DataFrame {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let max_n_cols = std::env::var(FMT_MAX_COLS)
+            .as_deref()
+            .unwrap_or("")
+            .parse()
+            .map_or(8, |n: i64| if n < 0 { self.width() } else { n as usize });
+
+        let max_n_rows = std::env::var(FMT_MAX_ROWS)
+            .as_deref()
+            .unwrap_or("")
+            .parse()
+            .map_or(8, |n: i64| if n < 0 { self.height() } else { n as usize });
+
+        let df_fmt = DataFrameFmt {
+            df: self,
+            max_n_rows,
+            max_n_cols,
+        };
+        Display::fmt(&df_fmt, f)
+    }
+}
+
+impl Display for DataFrameFmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let df = self.df;
         #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
         {
-            let height = self.height();
+            let (max_n_rows, max_n_cols) = (self.max_n_rows, self.max_n_cols);
+            let height = df.height();
             assert!(
-                self.columns.iter().all(|s| s.len() == height),
+                df.columns.iter().all(|s| s.len() == height),
                 "The column lengths in the DataFrame are not equal."
             );
             let str_truncate = std::env::var(FMT_STR_LEN)
@@ -397,24 +445,12 @@ impl Display for DataFrame {
                 .parse()
                 .unwrap_or(32);
 
-            let max_n_cols = std::env::var(FMT_MAX_COLS)
-                .as_deref()
-                .unwrap_or("")
-                .parse()
-                .map_or(8, |n: i64| if n < 0 { self.width() } else { n as usize });
-
-            let max_n_rows = std::env::var(FMT_MAX_ROWS)
-                .as_deref()
-                .unwrap_or("")
-                .parse()
-                .map_or(8, |n: i64| if n < 0 { height } else { n as usize });
-
-            let (n_first, n_last) = if self.width() > max_n_cols {
+            let (n_first, n_last) = if df.width() > max_n_cols {
                 ((max_n_cols + 1) / 2, max_n_cols / 2)
             } else {
-                (self.width(), 0)
+                (df.width(), 0)
             };
-            let reduce_columns = n_first + n_last < self.width();
+            let reduce_columns = n_first + n_last < df.width();
             let mut names = Vec::with_capacity(n_first + n_last + reduce_columns as usize);
 
             let field_to_str = |f: &Field| {
@@ -453,7 +489,7 @@ impl Display for DataFrame {
                 |l: usize| ColumnConstraint::LowerBoundary(comfy_table::Width::Fixed(l as u16));
 
             let mut constraints = Vec::with_capacity(n_first + n_last + reduce_columns as usize);
-            let fields = self.fields();
+            let fields = df.fields();
             for field in fields[0..n_first].iter() {
                 let (s, l) = field_to_str(field);
                 names.push(s);
@@ -463,7 +499,7 @@ impl Display for DataFrame {
                 names.push("…".into());
                 constraints.push(tbl_lower_bounds(3));
             }
-            for field in fields[self.width() - n_last..].iter() {
+            for field in fields[df.width() - n_last..].iter() {
                 let (s, l) = field_to_str(field);
                 names.push(s);
                 constraints.push(tbl_lower_bounds(l));
@@ -501,34 +537,22 @@ impl Display for DataFrame {
                 if height > max_n_rows {
                     let mut rows = Vec::with_capacity(std::cmp::max(max_n_rows, 2));
                     for i in 0..std::cmp::max(max_n_rows / 2, 1) {
-                        let row = self
-                            .columns
-                            .iter()
-                            .map(|s| s.str_value(i).unwrap())
-                            .collect();
+                        let row = df.columns.iter().map(|s| s.str_value(i).unwrap()).collect();
                         rows.push(prepare_row(row, n_first, n_last, str_truncate));
                     }
                     let dots = rows[0].iter().map(|_| "…".to_string()).collect();
                     rows.push(dots);
                     if max_n_rows > 1 {
                         for i in (height - (max_n_rows + 1) / 2)..height {
-                            let row = self
-                                .columns
-                                .iter()
-                                .map(|s| s.str_value(i).unwrap())
-                                .collect();
+                            let row = df.columns.iter().map(|s| s.str_value(i).unwrap()).collect();
                             rows.push(prepare_row(row, n_first, n_last, str_truncate));
                         }
                     }
                     table.add_rows(rows);
                 } else {
                     for i in 0..height {
-                        if self.width() > 0 {
-                            let row = self
-                                .columns
-                                .iter()
-                                .map(|s| s.str_value(i).unwrap())
-                                .collect();
+                        if df.width() > 0 {
+                            let row = df.columns.iter().map(|s| s.str_value(i).unwrap()).collect();
                             table.add_row(prepare_row(row, n_first, n_last, str_truncate));
                         } else {
                             break;
@@ -536,7 +560,7 @@ impl Display for DataFrame {
                     }
                 }
             } else if height > 0 {
-                let dots: Vec<String> = self.columns.iter().map(|_| "…".to_string()).collect();
+                let dots: Vec<String> = df.columns.iter().map(|_| "…".to_string()).collect();
                 table.add_row(dots);
             }
 
@@ -590,7 +614,7 @@ impl Display for DataFrame {
             }
 
             // establish 'shape' information (above/below/hidden)
-            let shape_str = fmt_df_shape(&self.shape());
+            let shape_str = fmt_df_shape(&df.shape());
 
             if env_is_true(FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION) {
                 write!(f, "{table}")?;
@@ -606,7 +630,7 @@ impl Display for DataFrame {
             write!(
                 f,
                 "shape: {:?}\nto see more, compile with the 'fmt' or 'fmt_no_tty' feature",
-                self.shape()
+                df.shape()
             )?;
         }
 
@@ -1168,4 +1192,28 @@ ChunkedArray: 'name' [str]
             format!("{:?}", ca)
         );
     }
+
+    #[test]
+    #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+    fn test_fmt_with_limits() -> PolarsResult<()> {
+        let df = df![
+            "apple" => (0..10).collect::<Vec<i32>>(),
+            "banana" => (0..10).map(|v| v as f64).collect::<Vec<_>>(),
+            "cherry" => (0..10).map(|v| v.to_string()).collect::<Vec<_>>()
+        ]?;
+
+        let out = df.fmt_with_limits(4, 2);
+        assert!(out.starts_with("shape: (10, 3)"));
+        assert!(out.contains("apple") && out.contains("i32"));
+        assert!(out.contains("cherry") && out.contains("str"));
+        // the middle column and rows are elided
+        assert!(!out.contains("banana"));
+        assert!(out.contains('…'));
+        assert!(out.contains('8') && !out.contains('5'));
+
+        let out = df.fmt_with_limits(20, 20);
+        assert!(out.contains("banana") && out.contains("f64"));
+        assert!(!out.contains('…'));
+        Ok(())
+    }
 }
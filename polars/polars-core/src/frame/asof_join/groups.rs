@@ -963,4 +963,32 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_asof_by_excludes_other_groups() -> PolarsResult<()> {
+        let left = df![
+            "time" => [1i64, 1, 5, 5],
+            "id" => [1, 2, 1, 2]
+        ]?;
+        let right = df![
+            "time" => [0i64, 4],
+            "id" => [2, 1],
+            "value" => [20, 10]
+        ]?;
+
+        let out = left.join_asof_by(
+            &right,
+            "time",
+            "time",
+            ["id"],
+            ["id"],
+            AsofStrategy::Backward,
+            None,
+        )?;
+        assert_eq!(out.get_column_names(), &["time", "id", "value"]);
+        // the first row would match `time == 0` of id 2, but only rows of id 1 are searched
+        let value = out.column("value")?.i32()?;
+        assert_eq!(Vec::from(value), &[None, Some(20), Some(10), Some(20)]);
+        Ok(())
+    }
 }
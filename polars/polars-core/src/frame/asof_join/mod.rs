@@ -187,6 +187,9 @@ impl DataFrame {
 
     /// This is similar to a left-join except that we match on nearest key rather than equal keys.
     /// The keys must be sorted to perform an asof join
+    ///
+    /// To only match rows that share the values of other columns, e.g. an `id`, use
+    /// [`DataFrame::join_asof_by`].
     pub fn join_asof(
         &self,
         other: &DataFrame,
@@ -2,7 +2,7 @@ use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
 use crate::series::IsSorted;
-use crate::utils::{concat_df_unchecked, slice_offsets, CustomIterTools, NoNull};
+use crate::utils::{slice_offsets, CustomIterTools, NoNull};
 use crate::POOL;
 
 fn slice_take(
@@ -47,7 +47,16 @@ impl DataFrame {
         other: &DataFrame,
         slice: Option<(i64, usize)>,
         parallel: bool,
+        max_rows: Option<usize>,
     ) -> PolarsResult<(DataFrame, DataFrame)> {
+        if let Some(max_rows) = max_rows {
+            let n_rows = self.height().checked_mul(other.height());
+            polars_ensure!(
+                n_rows.map_or(false, |n_rows| n_rows <= max_rows),
+                ComputeError: "cross join of {} and {} rows exceeds the maximum of {} rows",
+                self.height(), other.height(), max_rows
+            );
+        }
         let n_rows_left = self.height() as IdxSize;
         let n_rows_right = other.height() as IdxSize;
         let Some(total_rows) = n_rows_left.checked_mul(n_rows_right) else {
@@ -67,24 +76,42 @@ impl DataFrame {
         //
         // left take idx:   000011112222
         // right take idx:  012301230123
+        //
+        // without a slice, this is every left value repeated 4 times and the right
+        // frame tiled 3 times.
 
-        let create_left_df = || {
-            // Safety:
-            // take left is in bounds
-            unsafe { self.take_unchecked(&take_left(total_rows, n_rows_right, slice)) }
+        let create_left_df = || match slice {
+            Some(_) => {
+                // Safety:
+                // take left is in bounds
+                unsafe { self.take_unchecked(&take_left(total_rows, n_rows_right, slice)) }
+            }
+            None => {
+                let repeat = |s: &Series| s.repeat_each(n_rows_right as usize);
+                let columns = if parallel {
+                    self.apply_columns_par(&repeat)
+                } else {
+                    self.apply_columns(&repeat)
+                };
+                DataFrame::new_no_checks(columns)
+            }
         };
 
-        let create_right_df = || {
-            // concatenation of dataframes is very expensive if we need to make the series mutable
-            // many times, these are atomic operations
-            // so we choose a different strategy at > 100 rows (arbitrarily small number)
-            if n_rows_left > 100 || slice.is_some() {
+        let create_right_df = || match slice {
+            Some(_) => {
                 // Safety:
                 // take right is in bounds
                 unsafe { other.take_unchecked(&take_right(total_rows, n_rows_right, slice)) }
-            } else {
-                let iter = (0..n_rows_left).map(|_| other);
-                concat_df_unchecked(iter)
+            }
+            // tiling the columns doesn't need an index of all output rows
+            None => {
+                let tile = |s: &Series| s.tile(n_rows_left as usize);
+                let columns = if parallel {
+                    other.apply_columns_par(&tile)
+                } else {
+                    other.apply_columns(&tile)
+                };
+                DataFrame::new_no_checks(columns)
             }
         };
         let (l_df, r_df) = if parallel {
@@ -102,7 +129,7 @@ impl DataFrame {
         other: &DataFrame,
         names: &[SmartString],
     ) -> PolarsResult<DataFrame> {
-        let (mut l_df, r_df) = self.cross_join_dfs(other, None, false)?;
+        let (mut l_df, r_df) = self.cross_join_dfs(other, None, false, None)?;
 
         unsafe {
             l_df.get_columns_mut().extend_from_slice(&r_df.columns);
@@ -126,7 +153,19 @@ impl DataFrame {
         suffix: Option<&str>,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
-        let (l_df, r_df) = self.cross_join_dfs(other, slice, true)?;
+        self.cross_join_with_max_rows(other, suffix, slice, None)
+    }
+
+    /// [`DataFrame::cross_join`] that returns an error before anything is allocated if the
+    /// product of both heights exceeds `max_rows`.
+    pub fn cross_join_with_max_rows(
+        &self,
+        other: &DataFrame,
+        suffix: Option<&str>,
+        slice: Option<(i64, usize)>,
+        max_rows: Option<usize>,
+    ) -> PolarsResult<DataFrame> {
+        let (l_df, r_df) = self.cross_join_dfs(other, slice, true, max_rows)?;
 
         _finish_join(l_df, r_df, suffix)
     }
@@ -159,4 +198,39 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cross_join_max_rows() -> PolarsResult<()> {
+        let df_a = df![
+            "a" => [1, 2, 3]
+        ]?;
+        let df_b = df![
+            "b" => [1, 2]
+        ]?;
+
+        assert!(df_a
+            .cross_join_with_max_rows(&df_b, None, None, Some(5))
+            .is_err());
+        let out = df_a.cross_join_with_max_rows(&df_b, None, None, Some(6))?;
+        assert_eq!(out.height(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_join_matches_take() -> PolarsResult<()> {
+        let df_a = df![
+            "a" => (0..150).map(|i| (i % 7 != 0).then_some(i)).collect::<Vec<_>>()
+        ]?;
+        let df_b = df![
+            "b" => [Some("x"), None, Some("z")],
+            "c" => [Some(1.5), Some(2.5), None]
+        ]?;
+
+        let out = df_a.cross_join(&df_b, None, None)?;
+        // a slice takes by index
+        let expected = df_a.cross_join(&df_b, None, Some((0, 450)))?;
+        assert_eq!(out.height(), 450);
+        assert!(out.frame_equal_missing(&expected));
+        Ok(())
+    }
 }
@@ -49,6 +49,16 @@ fn prepare_dataframe_unsorted(by: &[Series]) -> DataFrame {
     )
 }
 
+/// The order of the groups in the output of a groupby.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GroupOrder {
+    /// Order the groups by their first occurrence in the [`DataFrame`].
+    #[default]
+    FirstAppearance,
+    /// Order the groups by their key values, ascending with nulls first.
+    Key,
+}
+
 impl DataFrame {
     pub fn groupby_with_series(
         &self,
@@ -123,6 +133,45 @@ impl DataFrame {
         let selected_keys = self.select_series(by)?;
         self.groupby_with_series(selected_keys, true, true)
     }
+
+    /// Group DataFrame using a Series column.
+    /// The groups are ordered by `order`, so that the output is the same on every run.
+    pub fn groupby_with_order<I, S>(&self, by: I, order: GroupOrder) -> PolarsResult<GroupBy>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let gb = self.groupby_stable(by)?;
+        match order {
+            GroupOrder::FirstAppearance => Ok(gb),
+            GroupOrder::Key => {
+                let keys = gb.keys();
+                let idx = if keys.len() == 1 {
+                    keys[0].arg_sort(SortOptions::default())
+                } else {
+                    crate::functions::arg_sort_by(&keys, &vec![false; keys.len()])?
+                };
+                let idx = idx.cont_slice().unwrap();
+                let groups = match gb.get_groups() {
+                    GroupsProxy::Idx(groups) => {
+                        let (first, all) = idx
+                            .iter()
+                            .map(|&i| {
+                                let i = i as usize;
+                                (groups.first()[i], groups.all()[i].clone())
+                            })
+                            .unzip();
+                        GroupsProxy::Idx(GroupsIdx::new(first, all, false))
+                    }
+                    GroupsProxy::Slice { groups, rolling } => GroupsProxy::Slice {
+                        groups: idx.iter().map(|&i| groups[i as usize]).collect(),
+                        rolling: *rolling,
+                    },
+                };
+                Ok(GroupBy::new(self, gb.selected_keys, groups, None))
+            }
+        }
+    }
 }
 
 /// Returned by a groupby operation on a DataFrame. This struct supports
@@ -235,6 +284,36 @@ impl<'df> GroupBy<'df> {
         &mut self.groups
     }
 
+    /// Iterate over the groups, yielding the key values of every group together with the row
+    /// indexes that belong to it.
+    ///
+    /// The key values are in the order of the `by` columns. The indexes refer to the rows of the
+    /// grouped `DataFrame`, so they can be applied to any frame of the same height.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: &DataFrame) -> PolarsResult<()> {
+    ///     let gb = df.groupby(["date"])?;
+    ///     for (key, group) in gb.iter_groups() {
+    ///         println!("{:?} has {} rows", key, group.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn iter_groups(&self) -> impl Iterator<Item = (Vec<AnyValue<'_>>, GroupsIndicator<'_>)> {
+        self.groups.iter().map(move |g| {
+            let first = g.first() as usize;
+            let key = self
+                .selected_keys
+                .iter()
+                .map(|s| s.get(first).unwrap())
+                .collect();
+            (key, g)
+        })
+    }
+
     pub fn take_groups(self) -> GroupsProxy {
         self.groups
     }
@@ -829,6 +908,51 @@ impl<'df> GroupBy<'df> {
         df.as_single_chunk();
         Ok(df)
     }
+
+    /// Aggregate `column` per group with a custom closure.
+    ///
+    /// The closure receives the values of a single group and returns its aggregated value. The
+    /// result holds the group keys and the aggregated column, which keeps its name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     // the range of the temperature per date
+    ///     df.groupby(["date"])?.agg("temp", |s| {
+    ///         match (s.max::<i32>(), s.min::<i32>()) {
+    ///             (Some(max), Some(min)) => AnyValue::Int32(max - min),
+    ///             _ => AnyValue::Null,
+    ///         }
+    ///     })
+    /// }
+    /// ```
+    pub fn agg<F>(&self, column: &str, f: F) -> PolarsResult<DataFrame>
+    where
+        F: for<'a> Fn(&'a Series) -> AnyValue<'a>,
+    {
+        let s = self.df.column(column)?;
+        let values = self
+            .get_groups()
+            .iter()
+            .map(|g| {
+                // safety
+                // groups are in bounds
+                let group = match g {
+                    GroupsIndicator::Idx(idx) => unsafe {
+                        s.take_iter_unchecked(&mut idx.1.iter().map(|i| *i as usize))
+                    },
+                    GroupsIndicator::Slice([first, len]) => s.slice(first as i64, len as usize),
+                };
+                f(&group).into_static()
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut cols = self.keys();
+        cols.push(Series::from_any_values(column, &values, false)?);
+        DataFrame::new(cols)
+    }
 }
 
 unsafe fn take_df(df: &DataFrame, g: GroupsIndicator) -> DataFrame {
@@ -910,7 +1034,7 @@ pub fn fmt_groupby_column(name: &str, method: GroupByMethod) -> String {
 mod test {
     use num_traits::FloatConst;
 
-    use crate::frame::groupby::groupby;
+    use crate::frame::groupby::{groupby, GroupsIndicator};
     use crate::prelude::*;
     use crate::utils::split_ca;
 
@@ -1155,6 +1279,75 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_groupby_iter_groups() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "c"],
+            "val" => [1, 10, 7, 4, 3]
+        ]?;
+        let other = df![
+            "x" => [0.5, 1.0, 1.5, 2.0, 2.5]
+        ]?;
+
+        let gb = df.groupby_stable(["g"])?;
+        let mut keys = vec![];
+        let mut sums = vec![];
+        for (key, group) in gb.iter_groups() {
+            let idx: Vec<usize> = match group {
+                GroupsIndicator::Idx((_, idx)) => idx.iter().map(|i| *i as usize).collect(),
+                GroupsIndicator::Slice([first, len]) => {
+                    (first as usize..(first + len) as usize).collect()
+                }
+            };
+            // apply the grouping to another frame with the same height
+            let taken = other.take_iter(idx.into_iter())?;
+            keys.push(key[0].clone().into_static()?);
+            sums.push(taken.column("x")?.sum::<f64>().unwrap());
+        }
+        assert_eq!(
+            keys,
+            &[
+                AnyValue::Utf8Owned("a".into()),
+                AnyValue::Utf8Owned("b".into()),
+                AnyValue::Utf8Owned("c".into())
+            ]
+        );
+        assert_eq!(sums, &[2.0, 3.0, 2.5]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_groupby_agg_closure() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "b", "c"],
+            "val" => [1, 10, 7, 4, 3]
+        ]?;
+
+        let out =
+            df.groupby_stable(["g"])?
+                .agg("val", |s| match (s.max::<i32>(), s.min::<i32>()) {
+                    (Some(max), Some(min)) => AnyValue::Int32(max - min),
+                    _ => AnyValue::Null,
+                })?;
+        assert_eq!(out.get_column_names(), &["g", "val"]);
+        assert_eq!(
+            Vec::from(out.column("g")?.utf8()?),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+        assert_eq!(
+            Vec::from(out.column("val")?.i32()?),
+            &[Some(6), Some(6), Some(0)]
+        );
+
+        assert!(df
+            .groupby(["g"])?
+            .agg("missing", |_| AnyValue::Null)
+            .is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     #[cfg(feature = "dtype-categorical")]
@@ -1173,4 +1366,50 @@ mod test {
         let _ = df.groupby(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_groupby_with_order() -> PolarsResult<()> {
+        let df = df![
+            "g" => [Some("c"), Some("a"), None, Some("c"), Some("b"), Some("a")],
+            "h" => [1, 2, 1, 1, 1, 3],
+            "v" => [1, 2, 3, 4, 5, 6]
+        ]?;
+
+        let run = |order| -> PolarsResult<DataFrame> {
+            df.groupby_with_order(["g"], order)?.select(["v"]).count()
+        };
+        let out = run(GroupOrder::FirstAppearance)?;
+        assert!(out.frame_equal_missing(&run(GroupOrder::FirstAppearance)?));
+        assert_eq!(
+            Vec::from(out.column("g")?.utf8()?),
+            &[Some("c"), Some("a"), None, Some("b")]
+        );
+        let out = run(GroupOrder::Key)?;
+        assert!(out.frame_equal_missing(&run(GroupOrder::Key)?));
+        assert_eq!(
+            Vec::from(out.column("g")?.utf8()?),
+            &[None, Some("a"), Some("b"), Some("c")]
+        );
+        assert_eq!(
+            Vec::from(out.column("v_count")?.idx()?),
+            &[Some(1), Some(2), Some(1), Some(2)]
+        );
+
+        // Use of deprecated `first()` for testing purposes
+        #[allow(deprecated)]
+        let out = df
+            .groupby_with_order(["g", "h"], GroupOrder::Key)?
+            .select(["v"])
+            .first()?;
+        assert_eq!(
+            Vec::from(out.column("g")?.utf8()?),
+            &[None, Some("a"), Some("a"), Some("b"), Some("c")]
+        );
+        assert_eq!(
+            Vec::from(out.column("v_first")?.i32()?),
+            &[Some(3), Some(2), Some(6), Some(5), Some(1)]
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,329 @@
+use std::sync::Arc;
+
+use super::*;
+
+/// Arguments that determine how a join is executed.
+///
+/// # Threads
+///
+/// Joins run on the global thread pool, [`POOL`](crate::POOL), which has `POLARS_MAX_THREADS`
+/// threads, or one per core if the variable isn't set. The pool is created on first use, so the
+/// variable must be set before polars runs any parallel operation. With `POLARS_MAX_THREADS=1`
+/// the hashing and probing of a join run on a single thread.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JoinArgs {
+    pub how: JoinType,
+    /// Suffix to add to duplicate column names of the right frame.
+    /// Defaults to `"_right"`.
+    pub suffix: Option<String>,
+    pub slice: Option<(i64, usize)>,
+    /// Sort the join tuples by left index and then by right index before
+    /// the frames are materialized. This makes the output of an inner join
+    /// independent of which relation is used to build the hash table.
+    ///
+    /// This requires a sort of all join tuples, so it is `O(n log n)` in the
+    /// number of output rows. Defaults to `false`.
+    ///
+    /// Without it, the output order is reproducible for the same inputs and the same number of
+    /// threads, but it may change with the number of threads.
+    pub maintain_order: bool,
+    /// Seed of the hasher that assigns the join keys to hash table partitions and buckets.
+    ///
+    /// The seed does not affect which rows match, only the internal bucketing. It is used
+    /// by outer joins and by joins on multiple keys. Inner and left joins on a single key
+    /// ignore it and always use a random seed; their row order follows the probe relation, so
+    /// it doesn't depend on the seed. With a fixed seed the row order of an outer join is
+    /// reproducible across runs. Defaults to `None`, a random seed.
+    pub hash_seed: Option<u64>,
+    /// Check the uniqueness of the join keys before joining.
+    /// Defaults to [`JoinValidation::ManyToMany`], which does not check anything.
+    pub validation: JoinValidation,
+    /// Remove the join key columns from the output. Defaults to `false`.
+    pub drop_keys: bool,
+    /// Output a single key column, named after the left key, instead of the keys of both frames.
+    ///
+    /// Inner and left joins take the key from the left frame, an outer join takes the key from
+    /// whichever frame has a value. If `false`, the right keys are kept as well and suffixed on
+    /// a name collision. Only affects inner, left and outer joins. Defaults to `true`.
+    pub coalesce_key: bool,
+    /// Error if an inner, left or outer join produces more than `explosion_factor` times the
+    /// rows of the larger input. This is checked before the output is materialized and catches
+    /// join keys that match far more often than intended. Defaults to `None`, no check.
+    pub explosion_factor: Option<f64>,
+    /// Error if a cross join would produce more than this many rows. This is checked before
+    /// anything is allocated. Defaults to `None`, no check.
+    pub cross_join_max_rows: Option<usize>,
+    /// Let null keys match each other. If `false`, a row in which a key is null matches no row,
+    /// so an outer join keeps such rows of both frames as separate rows, each with a null key.
+    /// Asof joins are not affected. Defaults to `true`.
+    ///
+    /// This holds for every key dtype. A boolean key thus has three states, `true`, `false` and
+    /// null, and by default the null keys match each other like those of a numeric key.
+    pub join_nulls: bool,
+    /// Replace the nulls in the join keys of both frames with this value before they are
+    /// matched, see [`JoinArgs::with_null_key_fill`]. Defaults to `None`.
+    pub null_key_fill: Option<AnyValue<'static>>,
+    /// The matches of a left row in a left join are output consecutively, in the row order of
+    /// the right frame. Set this to output them in reverse row order instead, e.g. to take the
+    /// last match with a downstream `first`. Defaults to `false`.
+    pub reverse_matches: bool,
+    /// The number of rows the join is expected to produce. Inner and left joins on a single key
+    /// reserve memory for this many join tuples up front, instead of growing from one tuple per
+    /// probed row. The hint doesn't change the output. Defaults to `None`.
+    pub expected_size: Option<usize>,
+    /// Match on the keys of both frames after they are passed through this function, e.g. to
+    /// trim whitespace or to round floats. The output keeps the original key values. Only
+    /// inner, left and outer joins support this. Defaults to `None`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub key_map: Option<JoinKeyMap>,
+}
+
+/// A function that maps a join key column to the values that are matched, see
+/// [`JoinArgs::map_keys`].
+#[derive(Clone)]
+pub struct JoinKeyMap(pub Arc<dyn Fn(&Series) -> Series + Send + Sync>);
+
+impl JoinKeyMap {
+    pub fn call(&self, s: &Series) -> Series {
+        (self.0)(s)
+    }
+}
+
+impl Debug for JoinKeyMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "JoinKeyMap")
+    }
+}
+
+impl PartialEq for JoinKeyMap {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl JoinArgs {
+    pub fn new(how: JoinType) -> Self {
+        Self {
+            how,
+            suffix: None,
+            slice: None,
+            maintain_order: false,
+            hash_seed: None,
+            validation: JoinValidation::ManyToMany,
+            drop_keys: false,
+            coalesce_key: true,
+            explosion_factor: None,
+            cross_join_max_rows: None,
+            join_nulls: true,
+            null_key_fill: None,
+            reverse_matches: false,
+            expected_size: None,
+            key_map: None,
+        }
+    }
+
+    pub fn with_suffix(mut self, suffix: Option<String>) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    pub fn with_maintain_order(mut self, maintain_order: bool) -> Self {
+        self.maintain_order = maintain_order;
+        self
+    }
+
+    pub fn with_hash_seed(mut self, hash_seed: Option<u64>) -> Self {
+        self.hash_seed = hash_seed;
+        self
+    }
+
+    pub fn with_validation(mut self, validation: JoinValidation) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    pub fn with_drop_keys(mut self, drop_keys: bool) -> Self {
+        self.drop_keys = drop_keys;
+        self
+    }
+
+    pub fn with_coalesce_key(mut self, coalesce_key: bool) -> Self {
+        self.coalesce_key = coalesce_key;
+        self
+    }
+
+    pub fn with_explosion_factor(mut self, explosion_factor: Option<f64>) -> Self {
+        self.explosion_factor = explosion_factor;
+        self
+    }
+
+    pub fn with_cross_join_max_rows(mut self, cross_join_max_rows: Option<usize>) -> Self {
+        self.cross_join_max_rows = cross_join_max_rows;
+        self
+    }
+
+    pub fn with_join_nulls(mut self, join_nulls: bool) -> Self {
+        self.join_nulls = join_nulls;
+        self
+    }
+
+    pub fn with_reverse_matches(mut self, reverse_matches: bool) -> Self {
+        self.reverse_matches = reverse_matches;
+        self
+    }
+
+    pub fn with_expected_size(mut self, expected_size: Option<usize>) -> Self {
+        self.expected_size = expected_size;
+        self
+    }
+
+    /// Replace the nulls in the join keys of both frames with `value`, e.g. `0` or `""`.
+    ///
+    /// A null key then matches the rows whose key is `value`, and the key columns of the output
+    /// contain `value` instead of null. Other columns are not affected. Because no null keys are
+    /// left to match, [`JoinArgs::join_nulls`] has no effect: null keys match each other even if
+    /// it is `false`. `value` is cast to the dtype of each key. `None` keeps the null keys.
+    pub fn with_null_key_fill(mut self, value: Option<AnyValue<'static>>) -> Self {
+        self.null_key_fill = value;
+        self
+    }
+
+    /// Pass the keys of both frames through `f` before they are matched.
+    ///
+    /// `f` must return a series of the same length, and the same dtype for both frames.
+    pub fn map_keys<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Series) -> Series + Send + Sync + 'static,
+    {
+        self.key_map = Some(JoinKeyMap(Arc::new(f)));
+        self
+    }
+}
+
+/// The expected relation between the join keys of the left and the right frame.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinValidation {
+    /// No check is done.
+    #[default]
+    ManyToMany,
+    /// The keys of the right frame must be unique.
+    ManyToOne,
+    /// The keys of the left frame must be unique.
+    OneToMany,
+    /// The keys of both frames must be unique.
+    OneToOne,
+}
+
+impl JoinValidation {
+    /// Check that the join keys are unique on the sides this validation requires.
+    ///
+    /// The error names the first key value, in row order, that occurs more than once.
+    pub fn validate(&self, left_on: &[Series], right_on: &[Series]) -> PolarsResult<()> {
+        use JoinValidation::*;
+        let (check_left, check_right) = match self {
+            ManyToMany => (false, false),
+            ManyToOne => (false, true),
+            OneToMany => (true, false),
+            OneToOne => (true, true),
+        };
+        if check_left {
+            validate_unique_keys(left_on, "left", *self)?;
+        }
+        if check_right {
+            validate_unique_keys(right_on, "right", *self)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for JoinValidation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use JoinValidation::*;
+        let val = match self {
+            ManyToMany => "m:m",
+            ManyToOne => "m:1",
+            OneToMany => "1:m",
+            OneToOne => "1:1",
+        };
+        write!(f, "{val}")
+    }
+}
+
+fn validate_unique_keys(
+    keys: &[Series],
+    side: &str,
+    validation: JoinValidation,
+) -> PolarsResult<()> {
+    let df = DataFrame::new_no_checks(keys.to_vec());
+    let groups = df
+        .groupby_with_series(keys.to_vec(), true, false)?
+        .take_groups();
+    if groups.len() == df.height() {
+        return Ok(());
+    }
+    let first = groups
+        .iter()
+        .filter(|g| g.len() > 1)
+        .map(|g| g.first())
+        .min()
+        .unwrap() as usize;
+    let key = keys
+        .iter()
+        .map(|s| s.get(first).map(|av| av.to_string()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let key = if key.len() == 1 {
+        key.into_iter().next().unwrap()
+    } else {
+        format!("({})", key.join(", "))
+    };
+    polars_bail!(
+        ComputeError: "join keys of the {} frame are not unique as required by the {} validation: \
+        key {} occurs more than once",
+        side, validation, key
+    )
+}
+
+impl From<JoinType> for JoinArgs {
+    fn from(how: JoinType) -> Self {
+        JoinArgs::new(how)
+    }
+}
+
+/// The hasher builder for the join keys, seeded with `hash_seed` if it is given.
+pub(super) fn join_random_state(hash_seed: Option<u64>) -> Option<RandomState> {
+    hash_seed.map(|seed| RandomState::with_seed(seed as usize))
+}
+
+/// Check that a join of `n_left` and `n_right` rows with `n_out` output rows stays within the
+/// `explosion_factor` of [`JoinArgs`].
+pub fn _check_join_explosion(
+    explosion_factor: Option<f64>,
+    n_out: usize,
+    n_left: usize,
+    n_right: usize,
+) -> PolarsResult<()> {
+    if let Some(factor) = explosion_factor {
+        let n_max = std::cmp::max(n_left, n_right);
+        polars_ensure!(
+            n_out as f64 <= factor * n_max as f64,
+            ComputeError: "join produced {} rows, more than {} times the {} rows of the larger \
+            input; check the join keys for unintended duplicates",
+            n_out, factor, n_max
+        );
+    }
+    Ok(())
+}
+
+/// Sort the inner join tuples by the left index and then by the right index.
+pub fn _sort_inner_join_tuples(left: &mut Vec<IdxSize>, right: &mut Vec<IdxSize>) {
+    let mut tuples = left
+        .iter()
+        .copied()
+        .zip(right.iter().copied())
+        .collect::<Vec<_>>();
+    // tuples are unique, so an unstable sort is deterministic
+    POOL.install(|| tuples.par_sort_unstable());
+    (*left, *right) = tuples.into_iter().unzip();
+}
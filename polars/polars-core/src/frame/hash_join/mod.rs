@@ -1,3 +1,4 @@
+mod args;
 pub(crate) mod multiple_keys;
 pub(super) mod single_keys;
 mod single_keys_dispatch;
@@ -12,6 +13,7 @@ use std::fmt::{Debug, Display, Formatter};
 use std::hash::{BuildHasher, Hash, Hasher};
 
 use ahash::RandomState;
+pub use args::*;
 #[cfg(feature = "chunked_ids")]
 use arrow::Either;
 use hashbrown::hash_map::{Entry, RawEntryMut};
@@ -46,7 +48,7 @@ use crate::hashing::{
     AsU64, BytesHash,
 };
 use crate::prelude::*;
-use crate::utils::{_set_partition_size, slice_slice, split_ca};
+use crate::utils::{_set_partition_size, _split_offsets, slice_slice, split_ca};
 use crate::POOL;
 
 pub type LeftJoinIds = (JoinIds, JoinOptIds);
@@ -65,6 +67,34 @@ pub type JoinIds = Vec<IdxSize>;
 /// [ChunkIdx, DfIdx]
 pub type ChunkId = [IdxSize; 2];
 
+/// Reverse the order of the right matches of every left row.
+///
+/// The tuples of a left join hold the matches of a left row consecutively, in the row order of
+/// the right frame.
+pub fn _reverse_left_join_matches<L: PartialEq, R>(left: &[L], right: &mut [R]) {
+    let mut start = 0;
+    while start < left.len() {
+        let n = left[start..]
+            .iter()
+            .take_while(|l| **l == left[start])
+            .count();
+        right[start..start + n].reverse();
+        start += n;
+    }
+}
+
+fn reverse_left_join_ids(ids: &mut LeftJoinIds) {
+    #[cfg(feature = "chunked_ids")]
+    match ids {
+        (Either::Left(left), Either::Left(right)) => _reverse_left_join_matches(left, right),
+        (Either::Left(left), Either::Right(right)) => _reverse_left_join_matches(left, right),
+        (Either::Right(left), Either::Left(right)) => _reverse_left_join_matches(left, right),
+        (Either::Right(left), Either::Right(right)) => _reverse_left_join_matches(left, right),
+    }
+    #[cfg(not(feature = "chunked_ids"))]
+    _reverse_left_join_matches(&ids.0, &mut ids.1)
+}
+
 pub fn default_join_ids() -> JoinOptIds {
     #[cfg(feature = "chunked_ids")]
     {
@@ -76,6 +106,86 @@ pub fn default_join_ids() -> JoinOptIds {
     }
 }
 
+/// Join ids of a left join in which none of the `n_left` rows has a match,
+/// e.g. because the right relation is empty.
+pub fn _left_join_ids_no_matches(n_left: usize) -> LeftJoinIds {
+    let left = (0..n_left as IdxSize).collect::<Vec<_>>();
+    let right = vec![None; n_left];
+    #[cfg(feature = "chunked_ids")]
+    {
+        (JoinIds::Left(left), JoinOptIds::Left(right))
+    }
+    #[cfg(not(feature = "chunked_ids"))]
+    {
+        (left, right)
+    }
+}
+
+/// Join tuples of an outer join in which no rows match, e.g. because
+/// one of the relations is empty.
+pub fn _outer_join_tuples_no_matches(
+    n_left: usize,
+    n_right: usize,
+) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+    (0..n_left as IdxSize)
+        .map(|idx| (Some(idx), None))
+        .chain((0..n_right as IdxSize).map(|idx| (None, Some(idx))))
+        .collect()
+}
+
+/// Indices of a semi or anti join in which none of the `n_left` rows has a match.
+#[cfg(feature = "semi_anti_join")]
+pub fn _semi_anti_join_ids_no_matches(n_left: usize, anti: bool) -> Vec<IdxSize> {
+    if anti {
+        (0..n_left as IdxSize).collect()
+    } else {
+        vec![]
+    }
+}
+
+/// Mask of the rows in which none of the join `keys` is null,
+/// or `None` if the keys contain no nulls.
+pub fn _join_keys_not_null(keys: &[Series]) -> Option<BooleanChunked> {
+    keys.iter()
+        .filter(|s| s.null_count() > 0)
+        .map(|s| s.is_not_null())
+        .reduce(|acc, mask| &acc & &mask)
+}
+
+/// Join tuples of an outer join in which the rows with a null key don't match, so that
+/// each of these rows of both relations ends up in a tuple of its own.
+pub fn _outer_join_tuples_unmatch_nulls(
+    mut opt_join_tuples: Vec<(Option<IdxSize>, Option<IdxSize>)>,
+    left_on: &[Series],
+    right_on: &[Series],
+) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+    let to_vec = |mask: BooleanChunked| mask.into_no_null_iter().collect::<Vec<_>>();
+    let left_valid = _join_keys_not_null(left_on).map(to_vec);
+    let right_valid = _join_keys_not_null(right_on).map(to_vec);
+    let is_valid = |valid: &Option<Vec<bool>>, idx: Option<IdxSize>| match (valid, idx) {
+        (Some(valid), Some(idx)) => valid[idx as usize],
+        _ => true,
+    };
+    opt_join_tuples
+        .retain(|(left, right)| is_valid(&left_valid, *left) && is_valid(&right_valid, *right));
+
+    if let Some(valid) = &left_valid {
+        opt_join_tuples.extend(
+            (0..valid.len() as IdxSize)
+                .filter(|idx| !valid[*idx as usize])
+                .map(|idx| (Some(idx), None)),
+        );
+    }
+    if let Some(valid) = &right_valid {
+        opt_join_tuples.extend(
+            (0..valid.len() as IdxSize)
+                .filter(|idx| !valid[*idx as usize])
+                .map(|idx| (None, Some(idx))),
+        );
+    }
+    opt_join_tuples
+}
+
 macro_rules! det_hash_prone_order {
     ($self:expr, $other:expr) => {{
         // The shortest relation will be used to create a hash table.
@@ -206,20 +316,55 @@ where
         let left_rand_access = self.take_rand();
         let right_rand_access = right_ca.take_rand();
 
+        let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), opt_join_tuples.len());
         opt_join_tuples
             .iter()
-            .map(|(opt_left_idx, opt_right_idx)| {
-                if let Some(left_idx) = opt_left_idx {
+            .for_each(|(opt_left_idx, opt_right_idx)| {
+                let opt_v = if let Some(left_idx) = opt_left_idx {
                     unsafe { left_rand_access.get_unchecked(*left_idx as usize) }
                 } else {
                     unsafe {
                         let right_idx = opt_right_idx.unwrap_unchecked();
                         right_rand_access.get_unchecked(right_idx as usize)
                     }
-                }
-            })
-            .collect_trusted::<ChunkedArray<T>>()
-            .into_series()
+                };
+                builder.append_option(opt_v);
+            });
+        builder.finish().into_series()
+    }
+}
+
+impl ZipOuterJoinColumn for Utf8Chunked {
+    fn zip_outer_join_column(
+        &self,
+        right_column: &Series,
+        opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
+    ) -> Series {
+        let right_ca = self.unpack_series_matching_type(right_column).unwrap();
+
+        let left_rand_access = self.take_rand();
+        let right_rand_access = right_ca.take_rand();
+
+        // estimate the bytes from the average key length
+        let n_keys = std::cmp::max(self.len() + right_ca.len(), 1);
+        let bytes_capacity =
+            (self.get_values_size() + right_ca.get_values_size()) / n_keys * opt_join_tuples.len();
+        let mut builder =
+            Utf8ChunkedBuilder::new(self.name(), opt_join_tuples.len(), bytes_capacity);
+        opt_join_tuples
+            .iter()
+            .for_each(|(opt_left_idx, opt_right_idx)| {
+                let opt_v = if let Some(left_idx) = opt_left_idx {
+                    unsafe { left_rand_access.get_unchecked(*left_idx as usize) }
+                } else {
+                    unsafe {
+                        let right_idx = opt_right_idx.unwrap_unchecked();
+                        right_rand_access.get_unchecked(right_idx as usize)
+                    }
+                };
+                builder.append_option(opt_v);
+            });
+        builder.finish().into_series()
     }
 }
 
@@ -255,7 +400,6 @@ macro_rules! impl_zip_outer_join {
     };
 }
 impl_zip_outer_join!(BooleanChunked);
-impl_zip_outer_join!(Utf8Chunked);
 impl_zip_outer_join!(BinaryChunked);
 
 impl ZipOuterJoinColumn for Float32Chunked {
@@ -288,38 +432,164 @@ impl ZipOuterJoinColumn for Float64Chunked {
     }
 }
 
+/// Minimal number of join tuples before the outer join key column is coalesced in parallel.
+const PAR_ZIP_OUTER_JOIN_MIN_LEN: usize = 1 << 16;
+
+/// Coalesce the outer join key column from `left` and `right`, splitting the join tuples over
+/// the thread pool if there are many of them.
+fn zip_outer_join_column_par(
+    left: &Series,
+    right: &Series,
+    opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
+) -> Series {
+    let n_threads = POOL.current_num_threads();
+    if n_threads == 1 || opt_join_tuples.len() < PAR_ZIP_OUTER_JOIN_MIN_LEN {
+        return left.zip_outer_join_column(right, opt_join_tuples);
+    }
+    let parts = POOL.install(|| {
+        _split_offsets(opt_join_tuples.len(), n_threads)
+            .into_par_iter()
+            .map(|(offset, len)| {
+                left.zip_outer_join_column(right, &opt_join_tuples[offset..offset + len])
+            })
+            .collect::<Vec<_>>()
+    });
+    let mut parts = parts.into_iter();
+    let mut out = parts.next().unwrap();
+    for part in parts {
+        // the parts all have the dtype of `left`
+        out.append(&part).unwrap();
+    }
+    out
+}
+
 pub fn _join_suffix_name(name: &str, suffix: &str) -> String {
     format!("{name}{suffix}")
 }
 
+/// Name of a right column that collides with a left column.
+///
+/// If the suffixed name is taken as well, e.g. because the left frame already is the
+/// result of a join with the same suffix, a numeric disambiguator is appended:
+/// `"rain_right_1"`, `"rain_right_2"`, etc.
+pub fn _join_suffix_name_unique<F>(name: &str, suffix: &str, is_taken: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    let new_name = _join_suffix_name(name, suffix);
+    if !is_taken(&new_name) {
+        return new_name;
+    }
+    (1usize..)
+        .map(|i| format!("{new_name}_{i}"))
+        .find(|candidate| !is_taken(candidate))
+        .unwrap()
+}
+
+/// The name of the right column that [`_join_suffix_name_unique`] turned into `name`, if
+/// `name` has the form `"{original}{suffix}"` or `"{original}{suffix}_{n}"`.
+pub fn _join_suffix_name_original<'a>(name: &'a str, suffix: &str) -> Option<&'a str> {
+    if let Some(original) = name.strip_suffix(suffix) {
+        return Some(original);
+    }
+    let (suffixed, n) = name.rsplit_once('_')?;
+    if n.is_empty() || !n.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    suffixed.strip_suffix(suffix)
+}
+
+/// The names of the columns of the left frame of a join.
+///
+/// The suffixes of the right columns are determined from these names, so they have to be
+/// unique. A join computes them once from its left input, before the join tuples are
+/// materialized, and passes them to [`_finish_join_with_left_names`].
+pub fn _join_left_names(df_left: &DataFrame) -> PolarsResult<PlHashSet<&str>> {
+    let mut left_names = PlHashSet::with_capacity(df_left.width());
+    for series in &df_left.columns {
+        polars_ensure!(
+            left_names.insert(series.name()),
+            Duplicate: "cannot join: column with name {:?} occurs more than once in the left frame",
+            series.name()
+        );
+    }
+    Ok(left_names)
+}
+
 /// Utility method to finish a join.
 #[doc(hidden)]
 pub fn _finish_join(
-    mut df_left: DataFrame,
+    df_left: DataFrame,
     mut df_right: DataFrame,
     suffix: Option<&str>,
 ) -> PolarsResult<DataFrame> {
-    let mut left_names = PlHashSet::with_capacity(df_left.width());
-
-    df_left.columns.iter().for_each(|series| {
-        left_names.insert(series.name());
-    });
+    let left_names = _join_left_names(&df_left)?;
+    suffix_right_names(&mut df_right, &left_names, suffix)?;
+    drop(left_names);
+    hstack_join(df_left, df_right)
+}
 
-    let mut rename_strs = Vec::with_capacity(df_right.width());
+/// Finish a join of which the names of the left columns are computed by [`_join_left_names`].
+#[doc(hidden)]
+pub fn _finish_join_with_left_names(
+    df_left: DataFrame,
+    mut df_right: DataFrame,
+    suffix: Option<&str>,
+    left_names: &PlHashSet<&str>,
+) -> PolarsResult<DataFrame> {
+    debug_assert!(
+        df_left.width() == left_names.len()
+            && df_left
+                .columns
+                .iter()
+                .all(|s| left_names.contains(s.name()))
+    );
+    suffix_right_names(&mut df_right, left_names, suffix)?;
+    hstack_join(df_left, df_right)
+}
 
-    df_right.columns.iter().for_each(|series| {
-        if left_names.contains(series.name()) {
-            rename_strs.push(series.name().to_owned())
+/// Rename the columns of `df_right` that collide with a left column.
+fn suffix_right_names(
+    df_right: &mut DataFrame,
+    left_names: &PlHashSet<&str>,
+    suffix: Option<&str>,
+) -> PolarsResult<()> {
+    let rename_strs = df_right
+        .columns
+        .iter()
+        .filter(|series| left_names.contains(series.name()))
+        .map(|series| series.name().to_owned())
+        .collect::<Vec<_>>();
+
+    if !rename_strs.is_empty() {
+        let suffix = suffix.unwrap_or("_right");
+        let mut taken = left_names
+            .iter()
+            .copied()
+            .chain(df_right.get_column_names())
+            .map(|name| name.to_string())
+            .collect::<PlHashSet<_>>();
+
+        for name in rename_strs {
+            let new_name =
+                _join_suffix_name_unique(&name, suffix, |candidate| taken.contains(candidate));
+            df_right.rename(&name, &new_name)?;
+            taken.insert(new_name);
         }
-    });
-    let suffix = suffix.unwrap_or("_right");
-
-    for name in rename_strs {
-        df_right.rename(&name, &_join_suffix_name(&name, suffix))?;
     }
+    Ok(())
+}
 
-    drop(left_names);
-    df_left.hstack_mut(&df_right.columns)?;
+fn hstack_join(mut df_left: DataFrame, mut df_right: DataFrame) -> PolarsResult<DataFrame> {
+    // only a frame without columns has no height of its own
+    polars_ensure!(
+        df_left.width() == 0 || df_right.width() == 0 || df_right.height() == df_left.height(),
+        ShapeMismatch: "unable to hstack a frame of height {} and a frame of height {}",
+        df_right.height(), df_left.height()
+    );
+    // the names are unique and the heights are checked, so the right columns can be moved over
+    // without the checks of `hstack_mut`
+    df_left.columns.append(&mut df_right.columns);
     Ok(df_left)
 }
 
@@ -366,12 +636,24 @@ impl DataFrame {
     #[cfg(not(feature = "chunked_ids"))]
     pub fn _finish_left_join(
         &self,
-        ids: LeftJoinIds,
+        mut ids: LeftJoinIds,
         other: &DataFrame,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
+        explosion_factor: Option<f64>,
+        reverse_matches: bool,
     ) -> PolarsResult<DataFrame> {
+        let left_names = _join_left_names(self)?;
+        if reverse_matches {
+            reverse_left_join_ids(&mut ids);
+        }
         let (left_idx, right_idx) = ids;
+        _check_join_explosion(
+            explosion_factor,
+            left_idx.len(),
+            self.height(),
+            other.height(),
+        )?;
         let materialize_left = || {
             let mut left_idx = &*left_idx;
             if let Some((offset, len)) = slice {
@@ -393,18 +675,26 @@ impl DataFrame {
         };
         let (df_left, df_right) = POOL.join(materialize_left, materialize_right);
 
-        _finish_join(df_left, df_right, suffix.as_deref())
+        _finish_join_with_left_names(df_left, df_right, suffix.as_deref(), &left_names)
     }
 
     #[cfg(feature = "chunked_ids")]
     pub fn _finish_left_join(
         &self,
-        ids: LeftJoinIds,
+        mut ids: LeftJoinIds,
         other: &DataFrame,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
+        explosion_factor: Option<f64>,
+        reverse_matches: bool,
     ) -> PolarsResult<DataFrame> {
+        let left_names = _join_left_names(self)?;
+        if reverse_matches {
+            reverse_left_join_ids(&mut ids);
+        }
         let (left_idx, right_idx) = ids;
+        let n_out = left_idx.as_ref().either(|idx| idx.len(), |idx| idx.len());
+        _check_join_explosion(explosion_factor, n_out, self.height(), other.height())?;
         let materialize_left = || match left_idx {
             JoinIds::Left(left_idx) => {
                 let mut left_idx = &*left_idx;
@@ -444,9 +734,10 @@ impl DataFrame {
         };
         let (df_left, df_right) = POOL.join(materialize_left, materialize_right);
 
-        _finish_join(df_left, df_right, suffix.as_deref())
+        _finish_join_with_left_names(df_left, df_right, suffix.as_deref(), &left_names)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn _left_join_from_series(
         &self,
         other: &DataFrame,
@@ -455,10 +746,27 @@ impl DataFrame {
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
         verbose: bool,
+        explosion_factor: Option<f64>,
+        expected_size: Option<usize>,
+        reverse_matches: bool,
     ) -> PolarsResult<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
 
+        // no need to hash if there is nothing to match
+        if self.height() == 0 || other.height() == 0 {
+            let ids = _left_join_ids_no_matches(self.height());
+            let other = other.drop(s_right.name())?;
+            return self._finish_left_join(
+                ids,
+                &other,
+                suffix,
+                slice,
+                explosion_factor,
+                reverse_matches,
+            );
+        }
+
         // ensure that the chunks are aligned otherwise we go OOB
         let mut left = self.clone();
         let mut s_left = s_left.clone();
@@ -472,8 +780,16 @@ impl DataFrame {
             right.as_single_chunk_par();
             s_right = s_right.rechunk();
         }
-        let ids = sort_or_hash_left(&s_left, &s_right, verbose);
-        left._finish_left_join(ids, &right.drop(s_right.name()).unwrap(), suffix, slice)
+        let ids = sort_or_hash_left(&s_left, &s_right, verbose, expected_size);
+        let right = right.drop(s_right.name()).unwrap();
+        left._finish_left_join(
+            ids,
+            &right,
+            suffix,
+            slice,
+            explosion_factor,
+            reverse_matches,
+        )
     }
 
     #[cfg(feature = "semi_anti_join")]
@@ -502,11 +818,16 @@ impl DataFrame {
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
 
-        let idx = s_left.hash_join_semi_anti(s_right, anti);
+        let idx = if s_left.is_empty() || s_right.is_empty() {
+            _semi_anti_join_ids_no_matches(s_left.len(), anti)
+        } else {
+            s_left.hash_join_semi_anti(s_right, anti)
+        };
         // Safety:
         // indices are in bounds
         Ok(unsafe { self._finish_anti_semi_join(&idx, slice) })
     }
+    #[allow(clippy::too_many_arguments)]
     pub fn _outer_join_from_series(
         &self,
         other: &DataFrame,
@@ -514,15 +835,33 @@ impl DataFrame {
         s_right: &Series,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
+        hash_seed: Option<u64>,
+        explosion_factor: Option<f64>,
+        join_nulls: bool,
     ) -> PolarsResult<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
-
-        // store this so that we can keep original column order.
-        let join_column_index = self.iter().position(|s| s.name() == s_left.name()).unwrap();
+        let left_names = _join_left_names(self)?;
 
         // Get the indexes of the joined relations
-        let opt_join_tuples = s_left.hash_join_outer(s_right);
+        let mut opt_join_tuples = if s_left.is_empty() || s_right.is_empty() {
+            _outer_join_tuples_no_matches(s_left.len(), s_right.len())
+        } else {
+            s_left.hash_join_outer(s_right, hash_seed)
+        };
+        if !join_nulls {
+            opt_join_tuples = _outer_join_tuples_unmatch_nulls(
+                opt_join_tuples,
+                &[s_left.clone()],
+                &[s_right.clone()],
+            );
+        }
+        _check_join_explosion(
+            explosion_factor,
+            opt_join_tuples.len(),
+            s_left.len(),
+            s_right.len(),
+        )?;
         let mut opt_join_tuples = &*opt_join_tuples;
 
         if let Some((offset, len)) = slice {
@@ -547,9 +886,11 @@ impl DataFrame {
             },
         );
 
-        let mut s = s_left
-            .to_physical_repr()
-            .zip_outer_join_column(&s_right.to_physical_repr(), opt_join_tuples);
+        let mut s = zip_outer_join_column_par(
+            &s_left.to_physical_repr(),
+            &s_right.to_physical_repr(),
+            opt_join_tuples,
+        );
         s.rename(s_left.name());
         let s = match s_left.dtype() {
             #[cfg(feature = "dtype-categorical")]
@@ -571,7 +912,98 @@ impl DataFrame {
             _ => s,
         };
 
-        unsafe { df_left.get_columns_mut().insert(join_column_index, s) };
-        _finish_join(df_left, df_right, suffix.as_deref())
+        // the coalesced key column always comes first
+        unsafe { df_left.get_columns_mut().insert(0, s) };
+        _finish_join_with_left_names(df_left, df_right, suffix.as_deref(), &left_names)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_zip_outer_join_column_par() {
+        let n = PAR_ZIP_OUTER_JOIN_MIN_LEN * 2 + 7;
+        let left = Series::new(
+            "a",
+            (0..n)
+                .map(|i| (i % 3 != 0).then_some(i as i64))
+                .collect::<Vec<_>>(),
+        );
+        let right = Series::new("b", (0..n).map(|i| -(i as i64)).collect::<Vec<_>>());
+        let opt_join_tuples = (0..n as IdxSize)
+            .map(|i| match i % 4 {
+                0 => (None, Some(i)),
+                _ => (Some(n as IdxSize - 1 - i), Some(i)),
+            })
+            .collect::<Vec<_>>();
+
+        let expected = left.zip_outer_join_column(&right, &opt_join_tuples);
+        let out = zip_outer_join_column_par(&left, &right, &opt_join_tuples);
+        assert!(out.series_equal_missing(&expected));
+
+        let left = left.cast(&DataType::Utf8).unwrap();
+        let right = right.cast(&DataType::Utf8).unwrap();
+        let expected = left.zip_outer_join_column(&right, &opt_join_tuples);
+        let out = zip_outer_join_column_par(&left, &right, &opt_join_tuples);
+        assert!(out.series_equal_missing(&expected));
+    }
+
+    #[test]
+    fn test_zip_outer_join_column_nulls() {
+        let left = Series::new("a", [Some(1i32), None, Some(3)]);
+        let right = Series::new("b", [Some(10i32), None]);
+        let opt_join_tuples = [
+            (Some(2), Some(1)),
+            (None, Some(0)),
+            (Some(1), None),
+            (None, Some(1)),
+            (Some(0), Some(0)),
+        ];
+        let expected = Series::new("a", [Some(3i32), Some(10), None, None, Some(1)]);
+        let out = left.zip_outer_join_column(&right, &opt_join_tuples);
+        assert!(out.series_equal_missing(&expected));
+
+        let left = left.cast(&DataType::Utf8).unwrap();
+        let right = right.cast(&DataType::Utf8).unwrap();
+        let expected = expected.cast(&DataType::Utf8).unwrap();
+        let out = left.zip_outer_join_column(&right, &opt_join_tuples);
+        assert!(out.series_equal_missing(&expected));
+    }
+
+    #[test]
+    fn test_join_suffix_name_original() {
+        for name in ["rain_right", "rain_right_1", "rain_right_12"] {
+            assert_eq!(_join_suffix_name_original(name, "_right"), Some("rain"));
+        }
+        assert_eq!(_join_suffix_name_original("rain", "_right"), None);
+        assert_eq!(_join_suffix_name_original("rain_right_", "_right"), None);
+        assert_eq!(_join_suffix_name_original("rain_right_x", "_right"), None);
+        assert_eq!(
+            _join_suffix_name_original(
+                &_join_suffix_name_unique("rain", "_right", |c| c == "rain_right"),
+                "_right"
+            ),
+            Some("rain")
+        );
+    }
+
+    #[test]
+    fn test_finish_join_heights() {
+        let empty = DataFrame::new(vec![Series::new_empty("a", &DataType::Int32)]).unwrap();
+        let right = DataFrame::new(vec![Series::new("b", [1i32, 2])]).unwrap();
+        // a left frame with columns has a height, even if that is zero
+        assert!(_finish_join(empty.clone(), right.clone(), None).is_err());
+        assert!(_finish_join(right.clone(), empty, None).is_err());
+
+        let out = _finish_join(DataFrame::default(), right.clone(), None).unwrap();
+        assert_eq!(out.shape(), (2, 1));
+        let out = _finish_join(right.clone(), DataFrame::default(), None).unwrap();
+        assert_eq!(out.shape(), (2, 1));
+
+        let out = _finish_join(right.clone(), right, None).unwrap();
+        assert_eq!(out.get_column_names(), &["b", "b_right"]);
     }
 }
@@ -179,6 +179,7 @@ pub fn _inner_join_multiple_keys(
     a: &mut DataFrame,
     b: &mut DataFrame,
     swap: bool,
+    hash_seed: Option<u64>,
 ) -> (Vec<IdxSize>, Vec<IdxSize>) {
     // we assume that the b DataFrame is the shorter relation.
     // b will be used for the build phase.
@@ -187,7 +188,8 @@ pub fn _inner_join_multiple_keys(
     let dfs_a = split_df(a, n_threads).unwrap();
     let dfs_b = split_df(b, n_threads).unwrap();
 
-    let (build_hashes, random_state) = df_rows_to_hashes_threaded_vertical(&dfs_b, None).unwrap();
+    let (build_hashes, random_state) =
+        df_rows_to_hashes_threaded_vertical(&dfs_b, join_random_state(hash_seed)).unwrap();
     let (probe_hashes, _) =
         df_rows_to_hashes_threaded_vertical(&dfs_a, Some(random_state)).unwrap();
 
@@ -251,7 +253,13 @@ pub fn private_left_join_multiple_keys(
 ) -> LeftJoinIds {
     let mut a = DataFrame::new_no_checks(_to_physical_and_bit_repr(a.get_columns()));
     let mut b = DataFrame::new_no_checks(_to_physical_and_bit_repr(b.get_columns()));
-    _left_join_multiple_keys(&mut a, &mut b, chunk_mapping_left, chunk_mapping_right)
+    _left_join_multiple_keys(
+        &mut a,
+        &mut b,
+        chunk_mapping_left,
+        chunk_mapping_right,
+        None,
+    )
 }
 
 pub fn _left_join_multiple_keys(
@@ -261,6 +269,7 @@ pub fn _left_join_multiple_keys(
     // only needed if we have non contiguous memory
     chunk_mapping_left: Option<&[ChunkId]>,
     chunk_mapping_right: Option<&[ChunkId]>,
+    hash_seed: Option<u64>,
 ) -> LeftJoinIds {
     // we should not join on logical types
     debug_assert!(!a.iter().any(|s| s.dtype().is_logical()));
@@ -270,7 +279,8 @@ pub fn _left_join_multiple_keys(
     let dfs_a = split_df(a, n_threads).unwrap();
     let dfs_b = split_df(b, n_threads).unwrap();
 
-    let (build_hashes, random_state) = df_rows_to_hashes_threaded_vertical(&dfs_b, None).unwrap();
+    let (build_hashes, random_state) =
+        df_rows_to_hashes_threaded_vertical(&dfs_b, join_random_state(hash_seed)).unwrap();
     let (probe_hashes, _) =
         df_rows_to_hashes_threaded_vertical(&dfs_a, Some(random_state)).unwrap();
 
@@ -392,6 +402,7 @@ pub(crate) fn create_build_table_semi_anti(
 pub(crate) fn semi_anti_join_multiple_keys_impl<'a>(
     a: &'a mut DataFrame,
     b: &'a mut DataFrame,
+    hash_seed: Option<u64>,
 ) -> impl ParallelIterator<Item = (IdxSize, bool)> + 'a {
     // we should not join on logical types
     debug_assert!(!a.iter().any(|s| s.dtype().is_logical()));
@@ -401,7 +412,8 @@ pub(crate) fn semi_anti_join_multiple_keys_impl<'a>(
     let dfs_a = split_df(a, n_threads).unwrap();
     let dfs_b = split_df(b, n_threads).unwrap();
 
-    let (build_hashes, random_state) = df_rows_to_hashes_threaded_vertical(&dfs_b, None).unwrap();
+    let (build_hashes, random_state) =
+        df_rows_to_hashes_threaded_vertical(&dfs_b, join_random_state(hash_seed)).unwrap();
     let (probe_hashes, _) =
         df_rows_to_hashes_threaded_vertical(&dfs_a, Some(random_state)).unwrap();
 
@@ -456,16 +468,24 @@ pub(crate) fn semi_anti_join_multiple_keys_impl<'a>(
 }
 
 #[cfg(feature = "semi_anti_join")]
-pub fn _left_anti_multiple_keys(a: &mut DataFrame, b: &mut DataFrame) -> Vec<IdxSize> {
-    semi_anti_join_multiple_keys_impl(a, b)
+pub fn _left_anti_multiple_keys(
+    a: &mut DataFrame,
+    b: &mut DataFrame,
+    hash_seed: Option<u64>,
+) -> Vec<IdxSize> {
+    semi_anti_join_multiple_keys_impl(a, b, hash_seed)
         .filter(|tpls| !tpls.1)
         .map(|tpls| tpls.0)
         .collect()
 }
 
 #[cfg(feature = "semi_anti_join")]
-pub fn _left_semi_multiple_keys(a: &mut DataFrame, b: &mut DataFrame) -> Vec<IdxSize> {
-    semi_anti_join_multiple_keys_impl(a, b)
+pub fn _left_semi_multiple_keys(
+    a: &mut DataFrame,
+    b: &mut DataFrame,
+    hash_seed: Option<u64>,
+) -> Vec<IdxSize> {
+    semi_anti_join_multiple_keys_impl(a, b, hash_seed)
         .filter(|tpls| tpls.1)
         .map(|tpls| tpls.0)
         .collect()
@@ -545,6 +565,7 @@ pub fn _outer_join_multiple_keys(
     a: &mut DataFrame,
     b: &mut DataFrame,
     swap: bool,
+    hash_seed: Option<u64>,
 ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
     // we assume that the b DataFrame is the shorter relation.
     // b will be used for the build phase.
@@ -556,7 +577,8 @@ pub fn _outer_join_multiple_keys(
     let dfs_a = split_df(a, n_threads).unwrap();
     let dfs_b = split_df(b, n_threads).unwrap();
 
-    let (build_hashes, random_state) = df_rows_to_hashes_threaded_vertical(&dfs_b, None).unwrap();
+    let (build_hashes, random_state) =
+        df_rows_to_hashes_threaded_vertical(&dfs_b, join_random_state(hash_seed)).unwrap();
     let (probe_hashes, _) =
         df_rows_to_hashes_threaded_vertical(&dfs_a, Some(random_state)).unwrap();
 
@@ -1,5 +1,9 @@
 use super::*;
 
+/// Build relations smaller than this are hashed into a single table on one thread.
+/// For these, the overhead of every thread traversing all keys outweighs the gain.
+const PARALLEL_BUILD_MIN_LEN: usize = 1 << 12;
+
 pub(crate) fn create_probe_table<T, IntoSlice>(
     keys: Vec<IntoSlice>,
 ) -> Vec<PlHashMap<T, Vec<IdxSize>>>
@@ -7,7 +11,46 @@ where
     T: Send + Hash + Eq + Sync + Copy + AsU64,
     IntoSlice: AsRef<[T]> + Send + Sync,
 {
-    let n_partitions = _set_partition_size();
+    create_probe_table_by(keys, |k: T| Some(k))
+}
+
+/// Create the probe table of the keys that `to_key` maps the elements of `keys` to.
+///
+/// Elements that are mapped to `None`, e.g. the nulls of a nullable key, are left out of the
+/// table, but still count for the row indices.
+pub(super) fn create_probe_table_by<T, K, IntoSlice, F>(
+    keys: Vec<IntoSlice>,
+    to_key: F,
+) -> Vec<PlHashMap<T, Vec<IdxSize>>>
+where
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+    K: Send + Sync + Copy,
+    IntoSlice: AsRef<[K]> + Send + Sync,
+    F: Fn(K) -> Option<T> + Send + Sync,
+{
+    let len = keys.iter().map(|k| k.as_ref().len()).sum::<usize>();
+    // a single partition accepts every key, so the probe side will always
+    // select that table.
+    let n_partitions = if len < PARALLEL_BUILD_MIN_LEN {
+        1
+    } else {
+        _set_partition_size()
+    };
+    create_probe_table_partitioned(keys, n_partitions, to_key)
+}
+
+fn create_probe_table_partitioned<T, K, IntoSlice, F>(
+    keys: Vec<IntoSlice>,
+    n_partitions: usize,
+    to_key: F,
+) -> Vec<PlHashMap<T, Vec<IdxSize>>>
+where
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+    K: Send + Sync + Copy,
+    IntoSlice: AsRef<[K]> + Send + Sync,
+    F: Fn(K) -> Option<T> + Send + Sync,
+{
+    debug_assert!(n_partitions.is_power_of_two());
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
@@ -32,8 +75,11 @@ where
                         let idx = cnt + offset;
                         cnt += 1;
 
+                        let Some(k) = to_key(*k) else {
+                            return;
+                        };
                         if this_partition(k.as_u64(), partition_no, n_partitions) {
-                            let entry = hash_tbl.entry(*k);
+                            let entry = hash_tbl.entry(k);
 
                             match entry {
                                 Entry::Vacant(entry) => {
@@ -58,7 +104,6 @@ where
 pub(super) fn probe_to_offsets<T, IntoSlice>(probe: &[IntoSlice]) -> Vec<usize>
 where
     IntoSlice: AsRef<[T]> + Send + Sync,
-    T: Send + Hash + Eq + Sync + Copy + AsU64,
 {
     probe
         .iter()
@@ -70,3 +115,54 @@ where
         })
         .collect()
 }
+
+/// The capacity to reserve for the join tuples of a single probe chunk.
+///
+/// Without a hint we assume one tuple per probed key. An `expected_size` of the whole join
+/// is divided over the chunks in proportion to their length.
+pub(super) fn probe_capacity(
+    chunk_len: usize,
+    probe_len: usize,
+    expected_size: Option<usize>,
+) -> usize {
+    match expected_size {
+        Some(expected_size) if probe_len > 0 => {
+            (expected_size as u128 * chunk_len as u128 / probe_len as u128) as usize
+        }
+        _ => chunk_len,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_partitioned_probe_table_equals_single_table() {
+        let keys = (0..10_000u64).map(|v| v % 1_000).collect::<Vec<_>>();
+        let keys = keys.chunks(3_000).collect::<Vec<_>>();
+
+        let single = create_probe_table_partitioned(keys.clone(), 1, Some);
+        let partitioned = create_probe_table_partitioned(keys, 8, Some);
+        assert_eq!(single.len(), 1);
+        assert_eq!(partitioned.len(), 8);
+
+        let single = &single[0];
+        assert_eq!(
+            partitioned.iter().map(|tbl| tbl.len()).sum::<usize>(),
+            single.len()
+        );
+        for (k, idx) in single {
+            let tbl =
+                unsafe { get_hash_tbl_threaded_join_partitioned(k.as_u64(), &partitioned, 8) };
+            assert_eq!(tbl.get(k), Some(idx));
+        }
+    }
+
+    #[test]
+    fn test_probe_capacity() {
+        assert_eq!(probe_capacity(250, 1_000, None), 250);
+        assert_eq!(probe_capacity(250, 1_000, Some(4_000)), 1_000);
+        assert_eq!(probe_capacity(0, 0, Some(10)), 0);
+    }
+}
@@ -1,6 +1,8 @@
+use std::borrow::Cow;
+
 use num_traits::NumCast;
 
-use super::single_keys_inner::hash_join_tuples_inner;
+use super::single_keys_inner::{hash_join_tuples_inner, hash_join_tuples_inner_by};
 use super::*;
 #[cfg(feature = "chunked_ids")]
 use crate::utils::create_chunked_index_mapping;
@@ -8,7 +10,7 @@ use crate::utils::create_chunked_index_mapping;
 impl Series {
     #[cfg(feature = "private")]
     #[doc(hidden)]
-    pub fn hash_join_left(&self, other: &Series) -> LeftJoinIds {
+    pub fn hash_join_left(&self, other: &Series, expected_size: Option<usize>) -> LeftJoinIds {
         let (lhs, rhs) = (self.to_physical_repr(), other.to_physical_repr());
 
         use DataType::*;
@@ -19,22 +21,22 @@ impl Series {
 
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_left(rhs)
+                lhs.hash_join_left(rhs, expected_size)
             }
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_left(rhs)
+                lhs.hash_join_left(rhs, expected_size)
             }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = lhs.bit_repr_large();
                     let rhs = rhs.bit_repr_large();
-                    num_group_join_left(&lhs, &rhs)
+                    num_group_join_left(&lhs, &rhs, expected_size)
                 } else {
                     let lhs = lhs.bit_repr_small();
                     let rhs = rhs.bit_repr_small();
-                    num_group_join_left(&lhs, &rhs)
+                    num_group_join_left(&lhs, &rhs, expected_size)
                 }
             }
         }
@@ -74,7 +76,11 @@ impl Series {
     }
 
     // returns the join tuples and whether or not the lhs tuples are sorted
-    pub(super) fn hash_join_inner(&self, other: &Series) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
+    pub(super) fn hash_join_inner(
+        &self,
+        other: &Series,
+        expected_size: Option<usize>,
+    ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
         let (lhs, rhs) = (self.to_physical_repr(), other.to_physical_repr());
 
         use DataType::*;
@@ -85,22 +91,22 @@ impl Series {
 
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_inner(rhs)
+                lhs.hash_join_inner(rhs, expected_size)
             }
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_inner(rhs)
+                lhs.hash_join_inner(rhs, expected_size)
             }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = self.bit_repr_large();
                     let rhs = other.bit_repr_large();
-                    num_group_join_inner(&lhs, &rhs)
+                    num_group_join_inner(&lhs, &rhs, expected_size)
                 } else {
                     let lhs = self.bit_repr_small();
                     let rhs = other.bit_repr_small();
-                    num_group_join_inner(&lhs, &rhs)
+                    num_group_join_inner(&lhs, &rhs, expected_size)
                 }
             }
         }
@@ -109,6 +115,7 @@ impl Series {
     pub(super) fn hash_join_outer(
         &self,
         other: &Series,
+        hash_seed: Option<u64>,
     ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
         let (lhs, rhs) = (self.to_physical_repr(), other.to_physical_repr());
 
@@ -120,22 +127,22 @@ impl Series {
 
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_outer(rhs)
+                lhs.hash_join_outer(rhs, hash_seed)
             }
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_outer(rhs)
+                lhs.hash_join_outer(rhs, hash_seed)
             }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = self.bit_repr_large();
                     let rhs = other.bit_repr_large();
-                    lhs.hash_join_outer(&rhs)
+                    lhs.hash_join_outer(&rhs, hash_seed)
                 } else {
                     let lhs = self.bit_repr_small();
                     let rhs = other.bit_repr_small();
-                    lhs.hash_join_outer(&rhs)
+                    lhs.hash_join_outer(&rhs, hash_seed)
                 }
             }
         }
@@ -173,6 +180,7 @@ where
 fn num_group_join_inner<T>(
     left: &ChunkedArray<T>,
     right: &ChunkedArray<T>,
+    expected_size: Option<usize>,
 ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool)
 where
     T: PolarsIntegerType,
@@ -180,29 +188,84 @@ where
     Option<T::Native>: AsU64,
 {
     let n_threads = POOL.current_num_threads();
+    let no_nulls = left.null_count() == 0 && right.null_count() == 0;
+    // Keys with nulls are collected through their `Option` iterator. If such a key
+    // consists of many chunks (e.g. a frame that was built incrementally), that
+    // iterator has to hop between the chunks for every element. We rechunk once so
+    // that every split is backed by a single contiguous array.
+    // This is free for keys that already are a single chunk, as `rechunk` only
+    // clones the `Arc`s in that case.
+    let (left, right) = if no_nulls {
+        (Cow::Borrowed(left), Cow::Borrowed(right))
+    } else {
+        (Cow::Owned(left.rechunk()), Cow::Owned(right.rechunk()))
+    };
+    let (left, right) = (left.as_ref(), right.as_ref());
     let (a, b, swap) = det_hash_prone_order!(left, right);
     let splitted_a = split_ca(a, n_threads).unwrap();
     let splitted_b = split_ca(b, n_threads).unwrap();
     match (
-        left.null_count() == 0,
-        right.null_count() == 0,
+        a.null_count() > 0,
+        b.null_count() > 0,
         left.chunks.len(),
         right.chunks.len(),
     ) {
-        (true, true, 1, 1) => {
+        (false, false, 1, 1) => {
             let keys_a = splitted_to_slice(&splitted_a);
             let keys_b = splitted_to_slice(&splitted_b);
-            (hash_join_tuples_inner(keys_a, keys_b, swap), !swap)
+            (
+                hash_join_tuples_inner(keys_a, keys_b, swap, expected_size),
+                !swap,
+            )
         }
-        (true, true, _, _) => {
+        (false, false, _, _) => {
             let keys_a = splitted_by_chunks(&splitted_a);
             let keys_b = splitted_by_chunks(&splitted_b);
-            (hash_join_tuples_inner(keys_a, keys_b, swap), !swap)
+            (
+                hash_join_tuples_inner(keys_a, keys_b, swap, expected_size),
+                !swap,
+            )
         }
-        _ => {
+        // A null key only finds a null key. If just one side has nulls, those rows never
+        // match, so the other side is hashed or probed as plain values and the nulls are
+        // skipped, instead of lifting both sides to `Option`.
+        (false, true, _, _) => {
+            let keys_a = splitted_to_slice(&splitted_a);
+            let keys_b = splitted_to_opt_vec(&splitted_b);
+            (
+                hash_join_tuples_inner_by(
+                    keys_a,
+                    keys_b,
+                    swap,
+                    expected_size,
+                    |k: T::Native| Some(k),
+                    |k: Option<T::Native>| k,
+                ),
+                !swap,
+            )
+        }
+        (true, false, _, _) => {
+            let keys_a = splitted_to_opt_vec(&splitted_a);
+            let keys_b = splitted_to_slice(&splitted_b);
+            (
+                hash_join_tuples_inner_by(
+                    keys_a,
+                    keys_b,
+                    swap,
+                    expected_size,
+                    |k: Option<T::Native>| k,
+                    |k: T::Native| Some(k),
+                ),
+                !swap,
+            )
+        }
+        (true, true, _, _) => {
             let keys_a = splitted_to_opt_vec(&splitted_a);
             let keys_b = splitted_to_opt_vec(&splitted_b);
-            (hash_join_tuples_inner(keys_a, keys_b, swap), !swap)
+            (
+                hash_join_tuples_inner(keys_a, keys_b, swap, expected_size),
+                !swap,
+            )
         }
     }
 }
@@ -243,7 +306,11 @@ fn create_mappings(
     (None, None)
 }
 
-fn num_group_join_left<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> LeftJoinIds
+fn num_group_join_left<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    expected_size: Option<usize>,
+) -> LeftJoinIds
 where
     T: PolarsIntegerType,
     T::Native: Hash + Eq + Send + AsU64,
@@ -261,7 +328,7 @@ where
         (0, 0, 1, 1) => {
             let keys_a = splitted_to_slice(&splitted_a);
             let keys_b = splitted_to_slice(&splitted_b);
-            hash_join_tuples_left(keys_a, keys_b, None, None)
+            hash_join_tuples_left(keys_a, keys_b, None, None, expected_size)
         }
         (0, 0, _, _) => {
             let keys_a = splitted_by_chunks(&splitted_a);
@@ -274,6 +341,7 @@ where
                 keys_b,
                 mapping_left.as_deref(),
                 mapping_right.as_deref(),
+                expected_size,
             )
         }
         _ => {
@@ -286,6 +354,7 @@ where
                 keys_b,
                 mapping_left.as_deref(),
                 mapping_right.as_deref(),
+                expected_size,
             )
         }
     }
@@ -296,7 +365,11 @@ where
     T: PolarsIntegerType + Sync,
     T::Native: Eq + Hash + NumCast,
 {
-    fn hash_join_outer(&self, other: &ChunkedArray<T>) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+    fn hash_join_outer(
+        &self,
+        other: &ChunkedArray<T>,
+        hash_seed: Option<u64>,
+    ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
         let n_partitions = _set_partition_size();
@@ -313,7 +386,7 @@ where
                     .iter()
                     .map(|ca| ca.into_no_null_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, join_random_state(hash_seed))
             }
             _ => {
                 let iters_a = splitted_a
@@ -324,7 +397,7 @@ where
                     .iter()
                     .map(|ca| ca.into_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, join_random_state(hash_seed))
             }
         }
     }
@@ -373,17 +446,21 @@ impl BinaryChunked {
     }
 
     // returns the join tuples and whether or not the lhs tuples are sorted
-    fn hash_join_inner(&self, other: &BinaryChunked) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
+    fn hash_join_inner(
+        &self,
+        other: &BinaryChunked,
+        expected_size: Option<usize>,
+    ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
         let (splitted_a, splitted_b, swap, hb) = self.prepare(other, true);
         let str_hashes_a = prepare_bytes(&splitted_a, &hb);
         let str_hashes_b = prepare_bytes(&splitted_b, &hb);
         (
-            hash_join_tuples_inner(str_hashes_a, str_hashes_b, swap),
+            hash_join_tuples_inner(str_hashes_a, str_hashes_b, swap, expected_size),
             !swap,
         )
     }
 
-    fn hash_join_left(&self, other: &BinaryChunked) -> LeftJoinIds {
+    fn hash_join_left(&self, other: &BinaryChunked, expected_size: Option<usize>) -> LeftJoinIds {
         let (splitted_a, splitted_b, _, hb) = self.prepare(other, false);
         let str_hashes_a = prepare_bytes(&splitted_a, &hb);
         let str_hashes_b = prepare_bytes(&splitted_b, &hb);
@@ -395,6 +472,7 @@ impl BinaryChunked {
             str_hashes_b,
             mapping_left.as_deref(),
             mapping_right.as_deref(),
+            expected_size,
         )
     }
 
@@ -410,7 +488,11 @@ impl BinaryChunked {
         }
     }
 
-    fn hash_join_outer(&self, other: &BinaryChunked) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
+    fn hash_join_outer(
+        &self,
+        other: &BinaryChunked,
+        hash_seed: Option<u64>,
+    ) -> Vec<(Option<IdxSize>, Option<IdxSize>)> {
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
         let n_partitions = _set_partition_size();
@@ -427,7 +509,7 @@ impl BinaryChunked {
                     .iter()
                     .map(|ca| ca.into_no_null_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, join_random_state(hash_seed))
             }
             _ => {
                 let iters_a = splitted_a
@@ -438,7 +520,7 @@ impl BinaryChunked {
                     .iter()
                     .map(|ca| ca.into_iter())
                     .collect::<Vec<_>>();
-                hash_join_tuples_outer(iters_a, iters_b, swap)
+                hash_join_tuples_outer(iters_a, iters_b, swap, join_random_state(hash_seed))
             }
         }
     }
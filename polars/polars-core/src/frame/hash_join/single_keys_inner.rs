@@ -1,6 +1,25 @@
-use super::single_keys::create_probe_table;
 use super::*;
-use crate::frame::hash_join::single_keys::probe_to_offsets;
+use crate::frame::hash_join::single_keys::{
+    create_probe_table_by, probe_capacity, probe_to_offsets,
+};
+
+/// Build relations up to this length are joined with a nested loop. For these, building
+/// a hash table costs more than comparing every probe key with every build key.
+///
+/// A probe of the hash table hashes the key and follows a bucket, the nested loop compares the
+/// key with a build slice that stays in the L1 cache. The cross-over point depends on the
+/// hardware, the `inner_join_small_build` benchmark in `benches/join.rs` measures both paths
+/// over the build length. It can be overridden with `POLARS_JOIN_NESTED_LOOP_MAX_LEN`, where
+/// `0` disables the nested loop for non-empty build relations. Values that don't parse as an
+/// unsigned integer are ignored.
+const NESTED_LOOP_MAX_LEN: usize = 64;
+
+fn nested_loop_max_len() -> usize {
+    std::env::var("POLARS_JOIN_NESTED_LOOP_MAX_LEN")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(NESTED_LOOP_MAX_LEN)
+}
 
 /// Probe the build table and add tuples to the results (inner join)
 pub(super) fn probe_inner<T, F>(
@@ -13,15 +32,45 @@ pub(super) fn probe_inner<T, F>(
 ) where
     T: Send + Hash + Eq + Sync + Copy + AsU64,
     F: Fn(IdxSize, IdxSize) -> (IdxSize, IdxSize),
+{
+    probe_inner_by(
+        probe,
+        |k: T| Some(k),
+        hash_tbls,
+        results,
+        local_offset,
+        n_tables,
+        swap_fn,
+    )
+}
+
+/// Probe the build table with the keys that `to_key` maps the elements of `probe` to. Elements
+/// that are mapped to `None` don't match.
+fn probe_inner_by<K, T, G, F>(
+    probe: &[K],
+    to_key: G,
+    hash_tbls: &[PlHashMap<T, Vec<IdxSize>>],
+    results: &mut Vec<(IdxSize, IdxSize)>,
+    local_offset: usize,
+    n_tables: u64,
+    swap_fn: F,
+) where
+    K: Copy,
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+    G: Fn(K) -> Option<T>,
+    F: Fn(IdxSize, IdxSize) -> (IdxSize, IdxSize),
 {
     assert!(hash_tbls.len().is_power_of_two());
     probe.iter().enumerate().for_each(|(idx_a, k)| {
+        let Some(k) = to_key(*k) else {
+            return;
+        };
         let idx_a = (idx_a + local_offset) as IdxSize;
         // probe table that contains the hashed value
         let current_probe_table =
             unsafe { get_hash_tbl_threaded_join_partitioned(k.as_u64(), hash_tbls, n_tables) };
 
-        let value = current_probe_table.get(k);
+        let value = current_probe_table.get(&k);
 
         if let Some(indexes_b) = value {
             let tuples = indexes_b.iter().map(|&idx_b| swap_fn(idx_a, idx_b));
@@ -35,19 +84,65 @@ pub(super) fn hash_join_tuples_inner<T, IntoSlice>(
     build: Vec<IntoSlice>,
     // Because b should be the shorter relation we could need to swap to keep left left and right right.
     swap: bool,
+    // expected number of join tuples, used to pre-allocate the results
+    expected_size: Option<usize>,
 ) -> (Vec<IdxSize>, Vec<IdxSize>)
 where
     IntoSlice: AsRef<[T]> + Send + Sync,
     T: Send + Hash + Eq + Sync + Copy + AsU64,
+{
+    hash_join_tuples_inner_by(
+        probe,
+        build,
+        swap,
+        expected_size,
+        |k: T| Some(k),
+        |k: T| Some(k),
+    )
+}
+
+/// Inner join the keys that `probe_key` and `build_key` map the elements of both relations to.
+///
+/// Elements that are mapped to `None` don't match. This lets a key with nulls skip them, while
+/// a key without nulls is hashed as plain values.
+pub(super) fn hash_join_tuples_inner_by<T, P, B, ProbeSlice, BuildSlice, PF, BF>(
+    probe: Vec<ProbeSlice>,
+    build: Vec<BuildSlice>,
+    swap: bool,
+    expected_size: Option<usize>,
+    probe_key: PF,
+    build_key: BF,
+) -> (Vec<IdxSize>, Vec<IdxSize>)
+where
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+    P: Send + Sync + Copy,
+    B: Send + Sync + Copy,
+    ProbeSlice: AsRef<[P]> + Send + Sync,
+    BuildSlice: AsRef<[B]> + Send + Sync,
+    PF: Fn(P) -> Option<T> + Send + Sync + Copy,
+    BF: Fn(B) -> Option<T> + Send + Sync + Copy,
 {
     // NOTE: see the left join for more elaborate comments
 
+    let build_len = build.iter().map(|b| b.as_ref().len()).sum::<usize>();
+    if build_len <= nested_loop_max_len() {
+        return nested_loop_join_tuples_inner(
+            probe,
+            build,
+            swap,
+            expected_size,
+            probe_key,
+            build_key,
+        );
+    }
+
     // first we hash one relation
-    let hash_tbls = create_probe_table(build);
+    let hash_tbls = create_probe_table_by(build, build_key);
 
     let n_tables = hash_tbls.len() as u64;
     debug_assert!(n_tables.is_power_of_two());
     let offsets = probe_to_offsets(&probe);
+    let probe_len = probe.iter().map(|p| p.as_ref().len()).sum::<usize>();
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
     POOL.install(|| {
@@ -58,13 +153,15 @@ where
                 let probe = probe.as_ref();
                 // local reference
                 let hash_tbls = &hash_tbls;
-                let mut results = Vec::with_capacity(probe.len());
+                let mut results =
+                    Vec::with_capacity(probe_capacity(probe.len(), probe_len, expected_size));
                 let local_offset = offset;
 
                 // branch is to hoist swap out of the inner loop.
                 if swap {
-                    probe_inner(
+                    probe_inner_by(
                         probe,
+                        probe_key,
                         hash_tbls,
                         &mut results,
                         local_offset,
@@ -72,8 +169,9 @@ where
                         |idx_a, idx_b| (idx_b, idx_a),
                     )
                 } else {
-                    probe_inner(
+                    probe_inner_by(
                         probe,
+                        probe_key,
                         hash_tbls,
                         &mut results,
                         local_offset,
@@ -87,3 +185,113 @@ where
             .unzip()
     })
 }
+
+/// Inner join a small build relation by comparing every probe key with every build key.
+/// Produces the same tuples in the same order as the hash join.
+fn nested_loop_join_tuples_inner<T, P, B, ProbeSlice, BuildSlice, PF, BF>(
+    probe: Vec<ProbeSlice>,
+    build: Vec<BuildSlice>,
+    swap: bool,
+    expected_size: Option<usize>,
+    probe_key: PF,
+    build_key: BF,
+) -> (Vec<IdxSize>, Vec<IdxSize>)
+where
+    T: Send + Hash + Eq + Sync + Copy + AsU64,
+    P: Send + Sync + Copy,
+    B: Send + Sync + Copy,
+    ProbeSlice: AsRef<[P]> + Send + Sync,
+    BuildSlice: AsRef<[B]> + Send + Sync,
+    PF: Fn(P) -> Option<T> + Send + Sync + Copy,
+    BF: Fn(B) -> Option<T> + Send + Sync + Copy,
+{
+    // the build keys that can match, with their row index
+    let build = build
+        .iter()
+        .flat_map(|b| b.as_ref().iter().copied())
+        .enumerate()
+        .filter_map(|(idx_b, k)| build_key(k).map(|k| (idx_b as IdxSize, k)))
+        .collect::<Vec<_>>();
+    let offsets = probe_to_offsets(&probe);
+    let probe_len = probe.iter().map(|p| p.as_ref().len()).sum::<usize>();
+    POOL.install(|| {
+        probe
+            .into_par_iter()
+            .zip(offsets)
+            .flat_map(|(probe, offset)| {
+                let build = &build;
+                let mut results = Vec::with_capacity(probe_capacity(
+                    probe.as_ref().len(),
+                    probe_len,
+                    expected_size,
+                ));
+                for (idx_a, k) in probe.as_ref().iter().enumerate() {
+                    let Some(k) = probe_key(*k) else {
+                        continue;
+                    };
+                    let idx_a = (idx_a + offset) as IdxSize;
+                    let matches = build
+                        .iter()
+                        .filter(|(_, b)| *b == k)
+                        .map(|(idx_b, _)| *idx_b);
+                    if swap {
+                        results.extend(matches.map(|idx_b| (idx_b, idx_a)));
+                    } else {
+                        results.extend(matches.map(|idx_b| (idx_a, idx_b)));
+                    }
+                }
+                results
+            })
+            .unzip()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nested_loop_equals_hash_join() {
+        // simple deterministic pseudo random keys
+        let mut state = 42u64;
+        let mut next = move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 33) % 50
+        };
+        for build_len in [1, 10, NESTED_LOOP_MAX_LEN] {
+            let probe = (0..1_000).map(|_| next()).collect::<Vec<_>>();
+            let build = (0..build_len).map(|_| next()).collect::<Vec<_>>();
+            let probe = probe.chunks(300).collect::<Vec<_>>();
+            let build = build.chunks(7).collect::<Vec<_>>();
+
+            for swap in [false, true] {
+                let nested = nested_loop_join_tuples_inner(
+                    probe.clone(),
+                    build.clone(),
+                    swap,
+                    None,
+                    Some,
+                    Some,
+                );
+                let hashed = {
+                    let hash_tbls = create_probe_table(build.clone());
+                    let n_tables = hash_tbls.len() as u64;
+                    let offsets = probe_to_offsets(&probe);
+                    let mut results = vec![];
+                    for (probe, offset) in probe.iter().zip(offsets) {
+                        let swap_fn = |idx_a, idx_b| {
+                            if swap {
+                                (idx_b, idx_a)
+                            } else {
+                                (idx_a, idx_b)
+                            }
+                        };
+                        probe_inner(probe, &hash_tbls, &mut results, offset, n_tables, swap_fn);
+                    }
+                    results.into_iter().unzip::<_, _, Vec<_>, Vec<_>>()
+                };
+                assert_eq!(nested, hashed);
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@ use polars_utils::flatten;
 
 use super::single_keys::create_probe_table;
 use super::*;
-use crate::frame::hash_join::single_keys::probe_to_offsets;
+use crate::frame::hash_join::single_keys::{probe_capacity, probe_to_offsets};
 
 #[cfg(feature = "chunked_ids")]
 unsafe fn apply_mapping(idx: Vec<IdxSize>, chunk_mapping: &[ChunkId]) -> Vec<ChunkId> {
@@ -97,6 +97,13 @@ pub(super) fn flatten_left_join_ids(result: Vec<LeftJoinIds>) -> LeftJoinIds {
     }
 }
 
+/// Probe the hash table built from the right relation with the left relation.
+///
+/// The left relation is never swapped: the probe chunks are processed in order and
+/// their results are concatenated in order. The output therefore follows the row order
+/// of the left relation, and the matches of a single left row are grouped together.
+/// The build table stores the indices of a key in insertion order, so these matches
+/// follow the row order of the right relation.
 pub(super) fn hash_join_tuples_left<T, IntoSlice>(
     probe: Vec<IntoSlice>,
     build: Vec<IntoSlice>,
@@ -104,6 +111,8 @@ pub(super) fn hash_join_tuples_left<T, IntoSlice>(
     // only needed if we have non contiguous memory
     chunk_mapping_left: Option<&[ChunkId]>,
     chunk_mapping_right: Option<&[ChunkId]>,
+    // expected number of join tuples, used to pre-allocate the results
+    expected_size: Option<usize>,
 ) -> LeftJoinIds
 where
     IntoSlice: AsRef<[T]> + Send + Sync,
@@ -114,6 +123,7 @@ where
 
     // we determine the offset so that we later know which index to store in the join tuples
     let offsets = probe_to_offsets(&probe);
+    let probe_len = probe.iter().map(|p| p.as_ref().len()).sum::<usize>();
 
     let n_tables = hash_tbls.len() as u64;
     debug_assert!(n_tables.is_power_of_two());
@@ -130,9 +140,11 @@ where
                 let hash_tbls = &hash_tbls;
                 let probe = probe.as_ref();
 
-                // assume the result tuples equal length of the no. of hashes processed by this thread.
-                let mut result_idx_left = Vec::with_capacity(probe.len());
-                let mut result_idx_right = Vec::with_capacity(probe.len());
+                // unless a size is expected, assume the result tuples equal length of the no. of
+                // hashes processed by this thread.
+                let capacity = probe_capacity(probe.len(), probe_len, expected_size);
+                let mut result_idx_left = Vec::with_capacity(capacity);
+                let mut result_idx_right = Vec::with_capacity(capacity);
 
                 probe.iter().enumerate().for_each(|(idx_a, k)| {
                     let idx_a = (idx_a + offset) as IdxSize;
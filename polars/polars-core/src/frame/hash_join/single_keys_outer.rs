@@ -64,6 +64,7 @@ pub(super) fn hash_join_tuples_outer<T, I, J>(
     a: Vec<I>,
     b: Vec<J>,
     swap: bool,
+    build_hasher: Option<RandomState>,
 ) -> Vec<(Option<IdxSize>, Option<IdxSize>)>
 where
     I: Iterator<Item = T> + Send + TrustedLen,
@@ -83,7 +84,7 @@ where
     let mut results = Vec::with_capacity(size);
 
     // prepare hash table
-    let mut hash_tbls = prepare_hashed_relation_threaded(b);
+    let mut hash_tbls = prepare_hashed_relation_threaded(b, build_hasher);
     let random_state = hash_tbls[0].hasher().clone();
 
     // we pre hash the probing values
@@ -178,8 +178,9 @@ pub fn _sort_or_hash_inner(
     s_left: &Series,
     s_right: &Series,
     _verbose: bool,
+    expected_size: Option<usize>,
 ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
-    s_left.hash_join_inner(s_right)
+    s_left.hash_join_inner(s_right, expected_size)
 }
 
 #[cfg(feature = "performant")]
@@ -187,6 +188,7 @@ pub fn _sort_or_hash_inner(
     s_left: &Series,
     s_right: &Series,
     verbose: bool,
+    expected_size: Option<usize>,
 ) -> ((Vec<IdxSize>, Vec<IdxSize>), bool) {
     // We check if keys are sorted.
     // - If they are we can do a sorted merge join
@@ -254,17 +256,27 @@ pub fn _sort_or_hash_inner(
             // set sorted to `false` as we descending sorted the left key.
             ((left, right), false)
         }
-        _ => s_left.hash_join_inner(s_right),
+        _ => s_left.hash_join_inner(s_right, expected_size),
     }
 }
 
 #[cfg(not(feature = "performant"))]
-pub(super) fn sort_or_hash_left(s_left: &Series, s_right: &Series, _verbose: bool) -> LeftJoinIds {
-    s_left.hash_join_left(s_right)
+pub(super) fn sort_or_hash_left(
+    s_left: &Series,
+    s_right: &Series,
+    _verbose: bool,
+    expected_size: Option<usize>,
+) -> LeftJoinIds {
+    s_left.hash_join_left(s_right, expected_size)
 }
 
 #[cfg(feature = "performant")]
-pub(super) fn sort_or_hash_left(s_left: &Series, s_right: &Series, verbose: bool) -> LeftJoinIds {
+pub(super) fn sort_or_hash_left(
+    s_left: &Series,
+    s_right: &Series,
+    verbose: bool,
+    expected_size: Option<usize>,
+) -> LeftJoinIds {
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
     let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
         .map(|s| s.parse::<f32>().unwrap())
@@ -305,6 +317,6 @@ pub(super) fn sort_or_hash_left(s_left: &Series, s_right: &Series, verbose: bool
             to_left_join_ids(left, right)
         }
         // don't reverse sort a left join key yet. Have to figure out how to set sorted flag
-        _ => s_left.hash_join_left(s_right),
+        _ => s_left.hash_join_left(s_right, expected_size),
     }
 }
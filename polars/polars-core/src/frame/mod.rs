@@ -7,6 +7,7 @@ use ahash::AHashSet;
 use polars_arrow::prelude::QuantileInterpolOptions;
 use rayon::prelude::*;
 
+use crate::chunked_array::ops::fill_null::fill_null_strategy_supported;
 use crate::chunked_array::ops::unique::is_unique_helper;
 use crate::prelude::*;
 #[cfg(feature = "describe")]
@@ -376,11 +377,15 @@ impl DataFrame {
     pub fn with_row_count(&self, name: &str, offset: Option<IdxSize>) -> PolarsResult<Self> {
         let mut columns = Vec::with_capacity(self.columns.len() + 1);
         let offset = offset.unwrap_or(0);
-
-        let mut ca = IdxCa::from_vec(
-            name,
-            (offset..(self.height() as IdxSize) + offset).collect(),
+        let height = self.height() as IdxSize;
+        // the last row number is `offset + height - 1`
+        polars_ensure!(
+            offset.checked_add(height.saturating_sub(1)).is_some(),
+            ComputeError: "row count starting at {} overflows for a DataFrame of height {}",
+            offset, self.height()
         );
+
+        let mut ca = IdxCa::from_vec(name, (0..height).map(|i| offset + i).collect());
         ca.set_sorted_flag(IsSorted::Ascending);
         columns.push(ca.into_series());
 
@@ -507,6 +512,9 @@ impl DataFrame {
 
     /// Get a reference to the `DataFrame` columns.
     ///
+    /// The columns can't be mutated through this view, so they always match the
+    /// [`DataFrame::schema`].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -520,6 +528,7 @@ impl DataFrame {
     /// # Ok::<(), PolarsError>(())
     /// ```
     #[inline]
+    #[doc(alias = "columns")]
     pub fn get_columns(&self) -> &[Series] {
         &self.columns
     }
@@ -550,6 +559,7 @@ impl DataFrame {
     /// assert_eq!(iterator.next(), None);
     /// # Ok::<(), PolarsError>(())
     /// ```
+    #[doc(alias = "iter_columns")]
     pub fn iter(&self) -> std::slice::Iter<'_, Series> {
         self.columns.iter()
     }
@@ -1084,6 +1094,26 @@ impl DataFrame {
         inner(self, names)
     }
 
+    /// Drop in place the columns for which `pred` returns `true`, given the column name and
+    /// dtype. E.g. drop the suffixed right columns after a join.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => [1, 2], "a_right" => [3, 4], "b" => ["x", "y"])?;
+    /// df.drop_columns_where(|name, _| name.ends_with("_right"));
+    /// assert_eq!(df.get_column_names(), &["a", "b"]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn drop_columns_where<F>(&mut self, pred: F) -> &mut Self
+    where
+        F: Fn(&str, &DataType) -> bool,
+    {
+        self.columns.retain(|s| !pred(s.name(), s.dtype()));
+        self
+    }
+
     fn insert_at_idx_no_name_check(
         &mut self,
         index: usize,
@@ -1623,8 +1653,42 @@ impl DataFrame {
         Ok(DataFrame::new_no_checks(new_col))
     }
 
+    /// Take `DataFrame` values by indexes from an iterator, validating all indexes up front.
+    ///
+    /// Returns an error naming the first index that is out of bounds. Once validated, the
+    /// values are gathered with [`DataFrame::take_iter_unchecked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// fn example(df: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     let iterator = (0..9).into_iter();
+    ///     df.take_iter_checked(iterator)
+    /// }
+    /// ```
+    pub fn take_iter_checked<I>(&self, iter: I) -> PolarsResult<Self>
+    where
+        I: Iterator<Item = usize> + Clone + Sync + TrustedLen,
+    {
+        let height = self.height();
+        if let Some((pos, idx)) = iter.clone().enumerate().find(|(_, idx)| *idx >= height) {
+            polars_bail!(
+                ComputeError: "take index {} at position {} is out of bounds for a DataFrame of height {}",
+                idx, pos, height
+            );
+        }
+        // Safety:
+        // all indexes are checked above
+        Ok(unsafe { self.take_iter_unchecked(iter) })
+    }
+
     /// Take `DataFrame` values by indexes from an iterator.
     ///
+    /// The joins gather with this and the other unchecked `take` variants, as the join tuples
+    /// they compute are always in bounds. Use [`DataFrame::take_iter_checked`] for untrusted
+    /// indexes.
+    ///
     /// # Safety
     ///
     /// This doesn't do any bound checking but checks null validity.
@@ -1770,6 +1834,38 @@ impl DataFrame {
         Ok(self)
     }
 
+    /// Rename every column with `f`, e.g. to lowercase all names.
+    ///
+    /// If two columns would get the same name, an error is returned and no column is renamed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df: DataFrame = df!("Key" => &[1, 2],
+    ///                             "Value_right" => &[3, 4])?;
+    /// df.rename_with(|name| name.trim_end_matches("_right").to_lowercase())?;
+    ///
+    /// assert_eq!(df.get_column_names(), &["key", "value"]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn rename_with<F: Fn(&str) -> String>(&mut self, f: F) -> PolarsResult<()> {
+        let names = self.columns.iter().map(|s| f(s.name())).collect::<Vec<_>>();
+        let mut seen = PlHashMap::with_capacity(names.len());
+        for (s, name) in self.columns.iter().zip(&names) {
+            if let Some(other) = seen.insert(name.as_str(), s.name()) {
+                polars_bail!(
+                    Duplicate: "renaming columns '{}' and '{}' both result in the name '{}'",
+                    other, s.name(), name
+                );
+            }
+        }
+        for (s, name) in self.columns.iter_mut().zip(&names) {
+            s.rename(name);
+        }
+        Ok(())
+    }
+
     /// Sort `DataFrame` in place by a column.
     pub fn sort_in_place(
         &mut self,
@@ -1985,6 +2081,10 @@ impl DataFrame {
 
     /// Apply a closure to a column. This is the recommended way to do in place modification.
     ///
+    /// The column keeps its name. A result of length `1` is broadcast to the height of the
+    /// [`DataFrame`]; any other length that differs from the height is an error, as is a
+    /// missing column.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -2424,9 +2524,50 @@ impl DataFrame {
     /// * Min fill (replace None with the minimum of the whole array)
     /// * Max fill (replace None with the maximum of the whole array)
     ///
+    /// Columns whose dtype doesn't support the strategy, e.g. `Mean` on a `Utf8` column, are left
+    /// untouched. Use [`DataFrame::fill_null_subset`] to fill only some columns.
+    ///
     /// See the method on [Series](../series/trait.SeriesTrait.html#method.fill_null) for more info on the `fill_null` operation.
     pub fn fill_null(&self, strategy: FillNullStrategy) -> PolarsResult<Self> {
-        let col = self.try_apply_columns_par(&|s| s.fill_null(strategy))?;
+        let col = self.try_apply_columns_par(&|s| {
+            if s.has_validity() && fill_null_strategy_supported(s.dtype(), strategy) {
+                s.fill_null(strategy)
+            } else {
+                Ok(s.clone())
+            }
+        })?;
+
+        Ok(DataFrame::new_no_checks(col))
+    }
+
+    /// Replace the None values of the columns in `subset` with `strategy`, see
+    /// [`DataFrame::fill_null`]. The other columns are left untouched.
+    ///
+    /// Different from [`DataFrame::fill_null`], an error is returned if the dtype of a column in
+    /// `subset` doesn't support the strategy.
+    pub fn fill_null_subset(
+        &self,
+        strategy: FillNullStrategy,
+        subset: &[&str],
+    ) -> PolarsResult<Self> {
+        let mut names = PlHashSet::with_capacity(subset.len());
+        for name in subset {
+            let s = &self.columns[self.check_name_to_idx(name)?];
+            polars_ensure!(
+                fill_null_strategy_supported(s.dtype(), strategy),
+                InvalidOperation: "cannot fill the nulls of column '{}' of dtype {} with \
+                strategy {:?}",
+                name, s.dtype(), strategy
+            );
+            names.insert(*name);
+        }
+        let col = self.try_apply_columns_par(&|s| {
+            if names.contains(s.name()) && s.has_validity() {
+                s.fill_null(strategy)
+            } else {
+                Ok(s.clone())
+            }
+        })?;
 
         Ok(DataFrame::new_no_checks(col))
     }
@@ -2508,12 +2649,25 @@ impl DataFrame {
 
         let original_schema = self.schema();
 
+        // the numeric columns get their minimum and maximum in a single pass
+        let (min, max): (Vec<_>, Vec<_>) = self
+            .columns
+            .iter()
+            .map(|s| match s.min_max() {
+                Some((min, max)) => (
+                    Series::from_any_values(s.name(), &[min], true).unwrap(),
+                    Series::from_any_values(s.name(), &[max], true).unwrap(),
+                ),
+                None => (s.min_as_series(), s.max_as_series()),
+            })
+            .unzip();
+
         let mut tmp: Vec<DataFrame> = vec![
             describe_cast(&count(self), &original_schema)?,
             describe_cast(&self.null_count(), &original_schema)?,
             describe_cast(&self.mean(), &original_schema)?,
             describe_cast(&self.std(1), &original_schema)?,
-            describe_cast(&self.min(), &original_schema)?,
+            describe_cast(&DataFrame::new_no_checks(min), &original_schema)?,
         ];
 
         for p in percentiles {
@@ -2525,7 +2679,10 @@ impl DataFrame {
         }
 
         // Keep order same as pandas
-        tmp.push(describe_cast(&self.max(), &original_schema)?);
+        tmp.push(describe_cast(
+            &DataFrame::new_no_checks(max),
+            &original_schema,
+        )?);
         headers.push("max".to_string());
 
         let mut summary = concat_df_unchecked(&tmp);
@@ -3124,6 +3281,22 @@ impl DataFrame {
     }
 
     /// Create a new `DataFrame` that shows the null counts per column.
+    ///
+    /// The result has a single row and a column of dtype `IDX_DTYPE` for every column
+    /// of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("a" => &[Some(1), None, None],
+    ///                         "b" => &[Some("x"), Some("y"), None])?;
+    /// let null_counts = df.null_count();
+    ///
+    /// assert_eq!(null_counts.shape(), (1, 2));
+    /// assert_eq!(null_counts.column("a")?.idx()?.get(0), Some(2));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
     #[must_use]
     pub fn null_count(&self) -> Self {
         let cols = self
@@ -3403,7 +3576,7 @@ impl From<DataFrame> for Vec<Series> {
 }
 
 // utility to test if we can vstack/extend the columns
-fn ensure_can_extend(left: &Series, right: &Series) -> PolarsResult<()> {
+pub(crate) fn ensure_can_extend(left: &Series, right: &Series) -> PolarsResult<()> {
     polars_ensure!(
         left.name() == right.name(),
         ShapeMismatch: "unable to vstack, column names don't match: {:?} and {:?}",
@@ -3519,6 +3692,21 @@ mod test {
         assert!(df.column("bar").is_ok())
     }
 
+    #[test]
+    fn test_duplicate_column_names() {
+        let days = Series::new("days", &[1, 2, 3]);
+        let err = DataFrame::new(vec![days.clone(), days.clone()]).unwrap_err();
+        assert!(matches!(err, PolarsError::Duplicate(_)));
+
+        let mut df = DataFrame::new(vec![days.clone()]).unwrap();
+        assert!(df.hstack(&[days.clone()]).is_err());
+        assert!(df
+            .hstack_mut(&[Series::new("temp", &[1, 2, 3]), days])
+            .is_err());
+        // a failed hstack leaves the frame untouched
+        assert_eq!(df.width(), 1);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn distinct() {
@@ -3609,6 +3797,83 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_with_row_count() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"]
+        ]?;
+        let out = df.with_row_count("row_nr", Some(10))?;
+        assert_eq!(out.get_column_names(), &["row_nr", "a", "b"]);
+        assert_eq!(
+            out.column("row_nr")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            (10..13).collect::<Vec<IdxSize>>()
+        );
+
+        // the name may not collide with an existing column
+        assert!(df.with_row_count("a", None).is_err());
+
+        // the last row number may be the maximum index
+        let out = df.with_row_count("row_nr", Some(IdxSize::MAX - 2))?;
+        assert_eq!(out.column("row_nr")?.idx()?.get(2), Some(IdxSize::MAX));
+        assert!(df.with_row_count("row_nr", Some(IdxSize::MAX - 1)).is_err());
+        assert_eq!(
+            df.clear()
+                .with_row_count("row_nr", Some(IdxSize::MAX))?
+                .height(),
+            0
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_take_iter_checked() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"]
+        ]?;
+        let out = df.take_iter_checked(vec![2, 0].into_iter())?;
+        assert!(out.frame_equal(&df![
+            "a" => [3, 1],
+            "b" => ["z", "x"]
+        ]?));
+
+        let err = df.take_iter_checked(vec![0, 3, 5].into_iter()).unwrap_err();
+        assert!(err.to_string().contains("index 3 at position 1"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply() -> PolarsResult<()> {
+        let mut df = df!(
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"]
+        )?;
+
+        df.apply("a", |s| s * 2)?;
+        assert_eq!(
+            Vec::from(df.column("a")?.i32()?),
+            &[Some(2), Some(4), Some(6)]
+        );
+
+        // the name of the column is kept
+        df.apply("a", |s| {
+            let mut s = s.cast(&DataType::Int64).unwrap();
+            s.rename("c");
+            s
+        })?;
+        assert_eq!(df.get_column_names(), &["a", "b"]);
+        assert_eq!(df.column("a")?.dtype(), &DataType::Int64);
+
+        assert!(df.apply("c", |s| s.clone()).is_err());
+        assert!(df.apply("a", |s| s.slice(0, 2)).is_err());
+        assert_eq!(df.column("a")?.len(), 3);
+        Ok(())
+    }
+
     #[test]
     fn test_empty_df_hstack() -> PolarsResult<()> {
         let mut base = df!(
@@ -3654,4 +3919,92 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "describe")]
+    fn test_df_describe_nan() -> PolarsResult<()> {
+        let df1: DataFrame = df!("float" => &[f64::NAN, 1.0, 3.0])?;
+        let df2 = df1.describe(None)?;
+        let describe = df2.column("describe")?.utf8()?;
+        let values = df2.column("float")?.f64()?;
+        let row = |name: &str| {
+            let idx = describe.into_iter().position(|d| d == Some(name)).unwrap();
+            values.get(idx)
+        };
+        // as in `min` and `max`, the NaN is skipped
+        let s = df1.column("float")?;
+        assert_eq!(row("min"), Some(1.0));
+        assert_eq!(row("max"), Some(3.0));
+        assert_eq!(row("min"), s.min());
+        assert_eq!(row("max"), s.max());
+        Ok(())
+    }
+
+    #[test]
+    fn test_drop_columns_where() -> PolarsResult<()> {
+        let mut df = df!(
+            "key" => [1, 2],
+            "a" => ["x", "y"],
+            "a_right" => ["z", "w"],
+            "b_right" => [1.0, 2.0],
+            "right" => [true, false]
+        )?;
+        df.drop_columns_where(|name, _| name.ends_with("_right"));
+        assert_eq!(df.get_column_names(), &["key", "a", "right"]);
+        assert_eq!(df.schema().len(), 3);
+        assert_eq!(df.height(), 2);
+
+        df.drop_columns_where(|_, dtype| !dtype.is_numeric());
+        assert_eq!(df.get_column_names(), &["key"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_with() -> PolarsResult<()> {
+        let mut df = df!(
+            "Key" => [1, 2],
+            "VALUE" => ["x", "y"]
+        )?;
+        df.rename_with(|name| name.to_lowercase())?;
+        assert_eq!(df.get_column_names(), &["key", "value"]);
+
+        let mut df = df!(
+            "a" => [1, 2],
+            "a_right" => [3, 4],
+            "b_right" => [5, 6]
+        )?;
+        let err = df.rename_with(|name| name.trim_end_matches("_right").to_string());
+        assert!(matches!(err, Err(PolarsError::Duplicate(_))));
+        // nothing is renamed on a collision
+        assert_eq!(df.get_column_names(), &["a", "a_right", "b_right"]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "partition_by")]
+    fn test_partition_by() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1, 2, 1, 2, 3, 1],
+            "b" => ["x", "x", "x", "y", "y", "x"],
+            "c" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+        )?;
+        let n_groups = df.groupby(["a", "b"])?.get_groups().len();
+        assert_eq!(n_groups, 4);
+
+        let parts = df.partition_by(["a", "b"])?;
+        assert_eq!(parts.len(), n_groups);
+        assert_eq!(
+            parts.iter().map(|df| df.height()).sum::<usize>(),
+            df.height()
+        );
+        for part in &parts {
+            assert_eq!(part.schema(), df.schema());
+            assert_eq!(part.column("a")?.n_unique()?, 1);
+            assert_eq!(part.column("b")?.n_unique()?, 1);
+        }
+
+        let parts = df.partition_by_stable(&["a", "b"])?;
+        assert_eq!(parts[0].column("c")?, &Series::new("c", &[1.0, 3.0, 6.0]));
+        Ok(())
+    }
 }
@@ -11,12 +11,15 @@ use arrow::types::simd::Simd;
 use num_traits::{Float, NumCast, ToPrimitive};
 #[cfg(feature = "concat_str")]
 use polars_arrow::prelude::ValueSize;
+use rayon::prelude::*;
 
 use crate::chunked_array::ops::sort::prepare_arg_sort;
+use crate::frame::ensure_can_extend;
 use crate::prelude::*;
 use crate::utils::coalesce_nulls;
 #[cfg(feature = "diagonal_concat")]
 use crate::utils::concat_df;
+use crate::POOL;
 
 /// Compute the covariance between two columns.
 pub fn cov_f<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>) -> Option<T::Native>
@@ -107,6 +110,33 @@ pub fn arg_sort_by(by: &[Series], descending: &[bool]) -> PolarsResult<IdxCa> {
     first.arg_sort_multiple(&by, &descending)
 }
 
+/// Take the first non-null value of the given series for every row.
+/// All series must have the same data type and length.
+#[cfg(feature = "zip_with")]
+pub fn coalesce(series: &[&Series]) -> PolarsResult<Series> {
+    let (first, rest) = series
+        .split_first()
+        .ok_or_else(|| polars_err!(NoData: "expected at least one series in `coalesce`"))?;
+    for s in rest {
+        polars_ensure!(
+            s.dtype() == first.dtype(),
+            SchemaMismatch: "cannot coalesce series of dtype {} and {}", first.dtype(), s.dtype()
+        );
+        polars_ensure!(
+            s.len() == first.len(),
+            ShapeMismatch: "cannot coalesce series of length {} and {}", first.len(), s.len()
+        );
+    }
+    let mut out = (*first).clone();
+    for s in rest {
+        if out.null_count() == 0 {
+            break;
+        }
+        out = out.zip_with(&out.is_not_null(), s)?;
+    }
+    Ok(out)
+}
+
 // utility to be able to also add literals to concat_str function
 #[cfg(feature = "concat_str")]
 enum IterBroadCast<'a> {
@@ -197,6 +227,44 @@ pub fn concat_str(s: &[Series], delimiter: &str) -> PolarsResult<Utf8Chunked> {
     Ok(builder.finish())
 }
 
+/// Concat `[DataFrame]`s vertically into a [`DataFrame`] with a single chunk per column.
+///
+/// All frames must have the same schema. Different from repeated [`DataFrame::vstack`], the
+/// values of every column are copied once into a buffer sized for the total number of rows.
+pub fn concat(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
+    let (first, rest) = dfs
+        .split_first()
+        .ok_or_else(|| polars_err!(ComputeError: "cannot concat empty dataframes"))?;
+    for df in rest {
+        polars_ensure!(
+            first.width() == df.width(),
+            ShapeMismatch:
+            "unable to concat a dataframe of width {} with a dataframe of width {}",
+            first.width(), df.width(),
+        );
+        first
+            .get_columns()
+            .iter()
+            .zip(df.get_columns())
+            .try_for_each(|(left, right)| ensure_can_extend(left, right))?;
+    }
+
+    let columns = POOL.install(|| {
+        (0..first.width())
+            .into_par_iter()
+            .map(|i| {
+                let mut s = first.get_columns()[i].clone();
+                for df in rest {
+                    s.append(&df.get_columns()[i])?;
+                }
+                // a single allocation of the total length
+                Ok(s.rechunk())
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    Ok(DataFrame::new_no_checks(columns))
+}
+
 /// Concat `[DataFrame]`s horizontally.
 #[cfg(feature = "horizontal_concat")]
 /// Concat horizontally and extend with null values if lengths don't match
@@ -317,6 +385,24 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "zip_with")]
+    fn test_coalesce() -> PolarsResult<()> {
+        let a = Series::new("a", &[Some(1), None, None, None]);
+        let b = Series::new("b", &[Some(10), Some(20), None, None]);
+        let c = Series::new("c", &[Some(100), Some(200), Some(300), None]);
+
+        let out = coalesce(&[&a, &b, &c])?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(20), Some(300), None]);
+
+        let d = Series::new("d", &[1.0f64, 2.0, 3.0, 4.0]);
+        assert!(coalesce(&[&a, &d]).is_err());
+        assert!(coalesce(&[&a, &b.slice(0, 2)]).is_err());
+        assert!(coalesce(&[]).is_err());
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "diagonal_concat")]
     fn test_diag_concat() -> PolarsResult<()> {
@@ -349,4 +435,36 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_concat() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1, 2],
+            "b" => ["a", "b"]
+        ]?;
+        let b = df![
+            "a" => [3],
+            "b" => ["c"]
+        ]?;
+        let c = df![
+            "a" => [4, 5],
+            "b" => [None, Some("e")]
+        ]?;
+
+        let out = concat(&[a.clone(), b, c])?;
+        let expected = df![
+            "a" => [1, 2, 3, 4, 5],
+            "b" => [Some("a"), Some("b"), Some("c"), None, Some("e")]
+        ]?;
+        assert!(out.frame_equal_missing(&expected));
+        assert_eq!(out.n_chunks(), 1);
+
+        let other = df![
+            "a" => [1.0],
+            "b" => ["a"]
+        ]?;
+        assert!(concat(&[a, other]).is_err());
+        assert!(concat(&[]).is_err());
+        Ok(())
+    }
 }
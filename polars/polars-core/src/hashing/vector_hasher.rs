@@ -364,6 +364,7 @@ impl<'a> PartialEq for BytesHash<'a> {
 
 pub(crate) fn prepare_hashed_relation_threaded<T, I>(
     iters: Vec<I>,
+    build_hasher: Option<RandomState>,
 ) -> Vec<HashMap<T, (bool, Vec<IdxSize>), RandomState>>
 where
     I: Iterator<Item = T> + Send + TrustedLen,
@@ -372,7 +373,8 @@ where
     let n_partitions = iters.len();
     assert!(n_partitions.is_power_of_two());
 
-    let (hashes_and_keys, build_hasher) = create_hash_and_keys_threaded_vectorized(iters, None);
+    let (hashes_and_keys, build_hasher) =
+        create_hash_and_keys_threaded_vectorized(iters, build_hasher);
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
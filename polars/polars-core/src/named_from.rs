@@ -19,6 +19,9 @@ pub trait NamedFrom<T, Phantom: ?Sized> {
 
 pub trait NamedFromOwned<T> {
     /// Initialize by name and values.
+    ///
+    /// A `Vec` of numeric values is moved into the [`Series`] without a copy. Missing values
+    /// are given as a `Vec` of `Option`s.
     fn from_vec(name: &str, _: T) -> Self;
 }
 
@@ -47,6 +50,36 @@ impl_named_from_owned!(Vec<u64>, UInt64Type);
 impl_named_from_owned!(Vec<f32>, Float32Type);
 impl_named_from_owned!(Vec<f64>, Float64Type);
 
+// these values have a different memory layout than arrow, so they are copied
+macro_rules! impl_named_from_owned_copied {
+    ($type:ty, $polars_type:ident, $method:ident) => {
+        impl NamedFromOwned<$type> for Series {
+            fn from_vec(name: &str, v: $type) -> Self {
+                ChunkedArray::<$polars_type>::$method(name, &v).into_series()
+            }
+        }
+    };
+}
+
+impl_named_from_owned_copied!(Vec<bool>, BooleanType, from_slice);
+impl_named_from_owned_copied!(Vec<String>, Utf8Type, from_slice);
+impl_named_from_owned_copied!(Vec<Option<bool>>, BooleanType, from_slice_options);
+impl_named_from_owned_copied!(Vec<Option<String>>, Utf8Type, from_slice_options);
+#[cfg(feature = "dtype-i8")]
+impl_named_from_owned_copied!(Vec<Option<i8>>, Int8Type, from_slice_options);
+#[cfg(feature = "dtype-i16")]
+impl_named_from_owned_copied!(Vec<Option<i16>>, Int16Type, from_slice_options);
+impl_named_from_owned_copied!(Vec<Option<i32>>, Int32Type, from_slice_options);
+impl_named_from_owned_copied!(Vec<Option<i64>>, Int64Type, from_slice_options);
+#[cfg(feature = "dtype-u8")]
+impl_named_from_owned_copied!(Vec<Option<u8>>, UInt8Type, from_slice_options);
+#[cfg(feature = "dtype-u16")]
+impl_named_from_owned_copied!(Vec<Option<u16>>, UInt16Type, from_slice_options);
+impl_named_from_owned_copied!(Vec<Option<u32>>, UInt32Type, from_slice_options);
+impl_named_from_owned_copied!(Vec<Option<u64>>, UInt64Type, from_slice_options);
+impl_named_from_owned_copied!(Vec<Option<f32>>, Float32Type, from_slice_options);
+impl_named_from_owned_copied!(Vec<Option<f64>>, Float64Type, from_slice_options);
+
 macro_rules! impl_named_from {
     ($type:ty, $polars_type:ident, $method:ident) => {
         impl<T: AsRef<$type>> NamedFrom<T, $type> for Series {
@@ -444,6 +477,43 @@ impl<T: IntoSeries> NamedFrom<T, T> for Series {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_from_vec() {
+        macro_rules! check_round_trip {
+            ($values:expr, $dtype:expr, $get:ident) => {{
+                let values = $values;
+                let s = Series::from_vec("a", values.clone());
+                assert_eq!(s.name(), "a");
+                assert_eq!(s.dtype(), &$dtype);
+                assert_eq!(s.$get().unwrap().into_iter().collect::<Vec<_>>(), values);
+            }};
+        }
+        check_round_trip!(vec![Some(1i32), None, Some(3)], DataType::Int32, i32);
+        check_round_trip!(vec![Some(1i64), None], DataType::Int64, i64);
+        check_round_trip!(vec![None, Some(1u32)], DataType::UInt32, u32);
+        check_round_trip!(vec![Some(u64::MAX), None], DataType::UInt64, u64);
+        check_round_trip!(vec![Some(1.5f32), None], DataType::Float32, f32);
+        check_round_trip!(vec![None, Some(-1.5f64)], DataType::Float64, f64);
+        check_round_trip!(vec![Some(true), None, Some(false)], DataType::Boolean, bool);
+        #[cfg(feature = "dtype-i8")]
+        check_round_trip!(vec![Some(-1i8), None], DataType::Int8, i8);
+        #[cfg(feature = "dtype-i16")]
+        check_round_trip!(vec![Some(-1i16), None], DataType::Int16, i16);
+        #[cfg(feature = "dtype-u8")]
+        check_round_trip!(vec![Some(1u8), None], DataType::UInt8, u8);
+        #[cfg(feature = "dtype-u16")]
+        check_round_trip!(vec![Some(1u16), None], DataType::UInt16, u16);
+
+        let s = Series::from_vec("a", vec![1i32, 2, 3]);
+        assert_eq!(Vec::from(s.i32().unwrap()), &[Some(1), Some(2), Some(3)]);
+        let s = Series::from_vec("a", vec![true, false]);
+        assert_eq!(Vec::from(s.bool().unwrap()), &[Some(true), Some(false)]);
+        let s = Series::from_vec("a", vec!["x".to_string(), "y".to_string()]);
+        assert_eq!(Vec::from(s.utf8().unwrap()), &[Some("x"), Some("y")]);
+        let s = Series::from_vec("a", vec![Some("x".to_string()), None]);
+        assert_eq!(Vec::from(s.utf8().unwrap()), &[Some("x"), None]);
+    }
+
     #[cfg(all(
         feature = "dtype-datetime",
         feature = "dtype-duration",
@@ -35,9 +35,9 @@ pub use crate::error::{polars_bail, polars_ensure, polars_err, PolarsError, Pola
 pub use crate::frame::asof_join::*;
 pub use crate::frame::explode::MeltArgs;
 pub(crate) use crate::frame::groupby::aggregations::*;
-pub use crate::frame::groupby::{GroupsIdx, GroupsProxy, GroupsSlice, IntoGroupsProxy};
-pub use crate::frame::hash_join::JoinType;
+pub use crate::frame::groupby::{GroupOrder, GroupsIdx, GroupsProxy, GroupsSlice, IntoGroupsProxy};
 pub(crate) use crate::frame::hash_join::*;
+pub use crate::frame::hash_join::{JoinArgs, JoinKeyMap, JoinType, JoinValidation};
 pub use crate::frame::{DataFrame, UniqueKeepStrategy};
 pub use crate::hashing::{FxHash, VecHash};
 pub use crate::named_from::{NamedFrom, NamedFromOwned};
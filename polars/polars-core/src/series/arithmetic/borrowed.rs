@@ -441,7 +441,7 @@ impl Sub for &Series {
     type Output = Series;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
+        let mut out = match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.sub(b))
@@ -450,22 +450,28 @@ impl Sub for &Series {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
                 lhs.subtract(rhs.as_ref()).expect("data types don't match")
             }
-        }
+        };
+        out.rename(self.name());
+        out
     }
 }
 
 impl Series {
+    /// Add `rhs` to `self`. Like the other arithmetic operators, the output is named after
+    /// the left operand.
     pub fn try_add(&self, rhs: &Series) -> PolarsResult<Series> {
-        match (self.dtype(), rhs.dtype()) {
+        let mut out = match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
-                Ok(_struct_arithmetic(self, rhs, |a, b| a.add(b)))
+                _struct_arithmetic(self, rhs, |a, b| a.add(b))
             }
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
-                lhs.add_to(rhs.as_ref())
+                lhs.add_to(rhs.as_ref())?
             }
-        }
+        };
+        out.rename(self.name());
+        Ok(out)
     }
 }
 impl Add for &Series {
@@ -485,7 +491,7 @@ impl Mul for &Series {
     /// let out = &s * &s;
     /// ```
     fn mul(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
+        let mut out = match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.mul(b))
@@ -494,7 +500,9 @@ impl Mul for &Series {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
                 lhs.multiply(rhs.as_ref()).expect("data types don't match")
             }
-        }
+        };
+        out.rename(self.name());
+        out
     }
 }
 
@@ -507,7 +515,7 @@ impl Div for &Series {
     /// let out = &s / &s;
     /// ```
     fn div(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
+        let mut out = match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.div(b))
@@ -516,7 +524,9 @@ impl Div for &Series {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
                 lhs.divide(rhs.as_ref()).expect("data types don't match")
             }
-        }
+        };
+        out.rename(self.name());
+        out
     }
 }
 
@@ -529,16 +539,110 @@ impl Rem for &Series {
     /// let out = &s / &s;
     /// ```
     fn rem(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
+        let mut out = match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.rem(b))
             }
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-                lhs.remainder(rhs.as_ref()).expect("data types don't match")
+                if lhs.dtype().is_integer() && needs_checked_rem(&rhs) {
+                    integer_division(&lhs, &rhs, |l, r| l.checked_rem(r))
+                        .expect("data types don't match")
+                } else {
+                    lhs.remainder(rhs.as_ref()).expect("data types don't match")
+                }
             }
-        }
+        };
+        out.rename(self.name());
+        out
+    }
+}
+
+/// Whether an integer remainder by `rhs` must be checked element-wise. A zero divisor gives a
+/// null, and a divisor of `-1` overflows the native remainder of the minimum value. Without
+/// those, the remainder is computed on the native values.
+fn needs_checked_rem(rhs: &Series) -> bool {
+    rhs.equal(0).unwrap().any() || (rhs.dtype().is_signed() && rhs.equal(-1).unwrap().any())
+}
+
+/// Apply the integer division `op` element-wise. `op` returns `None` for a zero divisor,
+/// which becomes a null in the output.
+fn integer_division<F>(lhs: &Series, rhs: &Series, op: F) -> PolarsResult<Series>
+where
+    F: Fn(i128, i128) -> Option<i128>,
+{
+    fn apply<T, F>(
+        lhs: &ChunkedArray<T>,
+        rhs: &ChunkedArray<T>,
+        op: F,
+    ) -> PolarsResult<ChunkedArray<T>>
+    where
+        T: PolarsIntegerType,
+        F: Fn(i128, i128) -> Option<i128>,
+    {
+        let op = |opt_l: Option<T::Native>, opt_r: Option<T::Native>| match (opt_l, opt_r) {
+            (Some(l), Some(r)) => {
+                op(l.to_i128().unwrap(), r.to_i128().unwrap()).and_then(NumCast::from)
+            }
+            _ => None,
+        };
+        let mut out: ChunkedArray<T> = match (lhs.len(), rhs.len()) {
+            (a, b) if a == b => lhs
+                .into_iter()
+                .zip(rhs)
+                .map(|(l, r)| op(l, r))
+                .collect_trusted(),
+            (_, 1) => {
+                let r = rhs.get(0);
+                lhs.into_iter().map(|l| op(l, r)).collect_trusted()
+            }
+            (1, _) => {
+                let l = lhs.get(0);
+                rhs.into_iter().map(|r| op(l, r)).collect_trusted()
+            }
+            (a, b) => polars_bail!(
+                ShapeMismatch: "cannot divide series of lengths {} and {}", a, b
+            ),
+        };
+        out.rename(lhs.name());
+        Ok(out)
+    }
+
+    let rhs = rhs.cast(lhs.dtype())?;
+    let out = with_match_physical_integer_polars_type!(lhs.dtype(), |$T| {
+        let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
+        let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
+        apply(lhs, rhs, op)?.into_series()
+    });
+    Ok(out)
+}
+
+impl Series {
+    /// Divide by `rhs` and round the quotient towards negative infinity, e.g. `-7` floor
+    /// divided by `2` is `-4`.
+    ///
+    /// Only integer dtypes are supported. A zero divisor gives a null, nulls propagate.
+    /// A quotient that doesn't fit in the dtype also gives a null, e.g. `i32::MIN` floor
+    /// divided by `-1`.
+    /// The `%` operator also gives a null for a zero integer divisor, but its remainder takes
+    /// the sign of `self`.
+    pub fn floor_div(&self, rhs: &Series) -> PolarsResult<Series> {
+        let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+        polars_ensure!(
+            lhs.dtype().is_integer(),
+            opq = floor_div,
+            self.dtype(),
+            rhs.dtype()
+        );
+        integer_division(&lhs, &rhs, |l, r| {
+            let q = l.checked_div(r)?;
+            if l % r != 0 && ((l < 0) != (r < 0)) {
+                Some(q - 1)
+            } else {
+                Some(q)
+            }
+        })
     }
 }
 
@@ -865,6 +969,48 @@ mod test {
         assert_eq!((1.div(&s)).name(), "foo");
     }
 
+    #[test]
+    fn test_arithmetic_output_name() {
+        let temp = Series::new("temp", [10.5, 20.0, 15.0]);
+        let rain = Series::new("rain", [1, 0, 2]);
+        let unit = Series::new("unit", [1.0]);
+
+        // the output is named after the left operand
+        assert_eq!((&temp + &rain).name(), "temp");
+        assert_eq!((&rain + &temp).name(), "rain");
+        assert_eq!((&temp - &rain).name(), "temp");
+        assert_eq!((&rain * &temp).name(), "rain");
+        assert_eq!((&rain / &temp).name(), "rain");
+        assert_eq!((&rain % &temp).name(), "rain");
+        assert_eq!((&unit * &temp).name(), "unit");
+        assert_eq!((temp.clone() + rain.clone()).name(), "temp");
+        assert_eq!((unit.clone() - temp.clone()).name(), "unit");
+        assert_eq!((&temp * 2).name(), "temp");
+
+        let out = (&temp + &rain).with_name("total");
+        assert_eq!(out.name(), "total");
+        let df = DataFrame::new(vec![temp, rain])
+            .unwrap()
+            .hstack(&[out])
+            .unwrap();
+        assert_eq!(df.get_column_names(), &["temp", "rain", "total"]);
+    }
+
+    #[test]
+    fn test_integer_rem_and_floor_div() {
+        let lhs = Series::new("a", [7i32, -7, i32::MIN]);
+        let out = &lhs % &Series::new("b", [2i32, 2, 3]);
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(1), Some(-1), Some(-2)]
+        );
+        let out = &lhs % &Series::new("b", [2i32, 0, -1]);
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(1), None, Some(0)]);
+
+        let out = lhs.floor_div(&Series::new("b", [2i32, 2, -1])).unwrap();
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(3), Some(-4), None]);
+    }
+
     #[test]
     #[cfg(feature = "checked_arithmetic")]
     fn test_checked_div() {
@@ -892,4 +1038,30 @@ mod test {
         let out = s_f64.checked_div_num(0.0f64).unwrap();
         assert_eq!(Vec::from(out.f64().unwrap()), &[None, None, None]);
     }
+
+    #[test]
+    fn test_integer_division_by_zero() -> PolarsResult<()> {
+        let lhs = Series::new("a", [Some(7), Some(-7), Some(7), None, Some(i32::MIN)]);
+        let rhs = Series::new("b", [Some(2), Some(2), Some(0), Some(2), Some(-1)]);
+
+        let out = lhs.floor_div(&rhs)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(3), Some(-4), None, None, None]
+        );
+        let out = &lhs % &rhs;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), Some(-1), None, None, Some(0)]
+        );
+
+        // broadcast a zero divisor
+        let zero = Series::new("", [0i32]);
+        assert_eq!(lhs.floor_div(&zero)?.null_count(), 5);
+        assert_eq!((&lhs % &zero).null_count(), 5);
+
+        assert!(Series::new("", [1.0f64]).floor_div(&zero).is_err());
+        Ok(())
+    }
 }
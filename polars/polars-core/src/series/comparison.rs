@@ -149,11 +149,8 @@ impl ChunkCompare<&Series> for Series {
 
                     self.categorical().unwrap().logical().equal(rhs)
                 } else {
-                    polars_bail!(
-                        ComputeError:
-                        "cannot compare categoricals originating from different sources; \
-                        consider setting a global string cache"
-                    );
+                    // the codes of different sources can't be compared, compare the strings
+                    self.cast(&Utf8)?.equal(&rhs.cast(&Utf8)?)?
                 }
             }
             (Null, Null, _, _) => BooleanChunked::full(self.name(), true, self.len()),
@@ -205,11 +202,8 @@ impl ChunkCompare<&Series> for Series {
 
                     self.categorical().unwrap().logical().not_equal(rhs)
                 } else {
-                    polars_bail!(
-                        ComputeError:
-                        "cannot compare categoricals originating from different sources; \
-                        consider setting a global string cache"
-                    );
+                    // the codes of different sources can't be compared, compare the strings
+                    self.cast(&Utf8)?.not_equal(&rhs.cast(&Utf8)?)?
                 }
             }
             (Null, Null, _, _) => BooleanChunked::full(self.name(), false, self.len()),
@@ -359,3 +353,47 @@ impl ChunkCompare<&str> for Series {
         compare_series_str(self, rhs, |lhs, rhs| lhs.lt_eq(rhs))
     }
 }
+
+/// Compare with a scalar. Any comparison with null is null, so a null `rhs` gives a mask of
+/// nulls, whatever the dtype of `lhs`.
+fn compare_series_any_value(
+    lhs: &Series,
+    rhs: AnyValue,
+    op: impl Fn(&Series, &Series) -> PolarsResult<BooleanChunked>,
+) -> PolarsResult<BooleanChunked> {
+    if let AnyValue::Null = rhs {
+        return Ok(BooleanChunked::full_null(lhs.name(), lhs.len()));
+    }
+    let rhs = Series::from_any_values("", &[rhs], false)?;
+    let mut out = op(lhs, &rhs)?;
+    out.rename(lhs.name());
+    Ok(out)
+}
+
+impl ChunkCompare<AnyValue<'_>> for Series {
+    type Item = PolarsResult<BooleanChunked>;
+
+    fn equal(&self, rhs: AnyValue) -> PolarsResult<BooleanChunked> {
+        compare_series_any_value(self, rhs, |lhs, rhs| lhs.equal(rhs))
+    }
+
+    fn not_equal(&self, rhs: AnyValue) -> PolarsResult<BooleanChunked> {
+        compare_series_any_value(self, rhs, |lhs, rhs| lhs.not_equal(rhs))
+    }
+
+    fn gt(&self, rhs: AnyValue) -> PolarsResult<BooleanChunked> {
+        compare_series_any_value(self, rhs, |lhs, rhs| lhs.gt(rhs))
+    }
+
+    fn gt_eq(&self, rhs: AnyValue) -> PolarsResult<BooleanChunked> {
+        compare_series_any_value(self, rhs, |lhs, rhs| lhs.gt_eq(rhs))
+    }
+
+    fn lt(&self, rhs: AnyValue) -> PolarsResult<BooleanChunked> {
+        compare_series_any_value(self, rhs, |lhs, rhs| lhs.lt(rhs))
+    }
+
+    fn lt_eq(&self, rhs: AnyValue) -> PolarsResult<BooleanChunked> {
+        compare_series_any_value(self, rhs, |lhs, rhs| lhs.lt_eq(rhs))
+    }
+}
@@ -95,6 +95,15 @@ impl Series {
         }
     }
 
+    /// Create a new [`Series`] from an Arrow array, e.g. one created by
+    /// [`Series::to_arrow_array`].
+    ///
+    /// Arrow types that polars stores differently, e.g. dates, dictionaries or strings with
+    /// 32-bit offsets, are converted to the polars representation. Other arrays are not copied.
+    pub fn from_arrow(name: &str, array: ArrayRef) -> PolarsResult<Series> {
+        Series::try_from((name, array))
+    }
+
     // Create a new Series without checking if the inner dtype of the chunks is correct
     // # Safety
     // The caller must ensure that the given `dtype` matches all the `ArrayRef` dtypes.
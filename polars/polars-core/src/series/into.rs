@@ -15,6 +15,19 @@ impl Series {
         &self.chunks()[chunk_idx] as &ArrayRef
     }
 
+    /// Convert the whole Series to a single array of the correct Arrow type.
+    ///
+    /// A Series with more than one chunk is rechunked first. Otherwise the values of dtypes
+    /// that polars and Arrow store alike, e.g. numbers and strings, are not copied. Use
+    /// [`Series::from_arrow`] to convert the array back.
+    pub fn to_arrow_array(&self) -> ArrayRef {
+        if self.n_chunks() == 1 {
+            self.to_arrow(0)
+        } else {
+            self.rechunk().to_arrow(0)
+        }
+    }
+
     /// Convert a chunk in the Series to the correct Arrow type.
     /// This conversion is needed because polars doesn't use a
     /// 1 on 1 mapping for logical/ categoricals, etc.
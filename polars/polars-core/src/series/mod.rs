@@ -15,6 +15,7 @@ mod series_trait;
 pub mod unstable;
 
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::sync::Arc;
@@ -24,6 +25,7 @@ use arrow::compute::aggregate::estimated_bytes_size;
 pub use from::*;
 pub use iterator::{SeriesIter, SeriesPhysIter};
 use num_traits::NumCast;
+use polars_arrow::kernels::rolling::{compare_fn_nan_max, compare_fn_nan_min};
 use rayon::prelude::*;
 pub use series_trait::{IsSorted, *};
 
@@ -31,6 +33,7 @@ pub use series_trait::{IsSorted, *};
 use crate::prelude::unique::rank::rank;
 #[cfg(feature = "zip_with")]
 use crate::series::arithmetic::coerce_lhs_rhs;
+use crate::series::ops::CastOptions;
 use crate::utils::{_split_offsets, split_ca, split_series, Wrap};
 use crate::POOL;
 
@@ -62,6 +65,8 @@ use crate::POOL;
 /// let out_mul = &s * &s;
 /// ```
 ///
+/// The output is named after the left operand. Use [`Series::with_name`] to name it otherwise.
+///
 /// Or with series and numbers.
 ///
 /// ```
@@ -208,11 +213,27 @@ impl Series {
         self
     }
 
+    /// Return this series with a new name.
+    ///
+    /// Arithmetic between two series is named after the left operand, so this is a short way
+    /// to name a computed column, e.g. `(&a - &b).with_name("diff")`.
+    pub fn with_name(mut self, name: &str) -> Series {
+        self.rename(name);
+        self
+    }
+
     /// Shrink the capacity of this array to fit its length.
     pub fn shrink_to_fit(&mut self) {
         self._get_inner_mut().shrink_to_fit()
     }
 
+    /// Count the values that are not null.
+    ///
+    /// This is `O(1)` as the null count of every chunk is read from its validity bitmap.
+    pub fn n_valid(&self) -> usize {
+        self.len() - self.null_count()
+    }
+
     /// Append in place. This is done by adding the chunks of `other` to this [`Series`].
     ///
     /// See [`ChunkedArray::append`] and [`ChunkedArray::extend`].
@@ -229,6 +250,9 @@ impl Series {
         Ok(self)
     }
 
+    /// Return a sorted copy of the [`Series`]. Nulls are placed first.
+    ///
+    /// Use [`Series::sort_with`] to place the nulls last.
     pub fn sort(&self, descending: bool) -> Self {
         self.sort_with(SortOptions {
             descending,
@@ -242,6 +266,10 @@ impl Series {
     }
 
     /// Cast `[Series]` to another `[DataType]`
+    ///
+    /// A boolean cast to a numeric type gives `1` for `true` and `0` for `false`, so the sum of a
+    /// cast mask counts its `true` values. A numeric cast to [`DataType::Boolean`] gives `false`
+    /// for zero and `true` for any other value. Nulls stay null in both directions.
     pub fn cast(&self, dtype: &DataType) -> PolarsResult<Self> {
         // best leave as is.
         if matches!(dtype, DataType::Unknown) {
@@ -260,6 +288,35 @@ impl Series {
         }
     }
 
+    /// Cast `[Series]` to another `[DataType]` with the given [`CastOptions`].
+    ///
+    /// With a `float_precision`, floats cast to `Utf8` are formatted with that fixed number of
+    /// decimals. All other casts are the same as [`Series::cast`].
+    pub fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Self> {
+        fn fmt_fixed<T>(ca: &ChunkedArray<T>, precision: usize) -> Series
+        where
+            T: PolarsNumericType,
+            T::Native: std::fmt::Display,
+        {
+            let mut out: Utf8Chunked = ca
+                .into_iter()
+                .map(|opt_v| opt_v.map(|v| format!("{v:.precision$}")))
+                .collect();
+            out.rename(ca.name());
+            out.into_series()
+        }
+
+        match (options.float_precision, self.dtype(), dtype) {
+            (Some(precision), DataType::Float32, DataType::Utf8) => {
+                Ok(fmt_fixed(self.f32().unwrap(), precision))
+            }
+            (Some(precision), DataType::Float64, DataType::Utf8) => {
+                Ok(fmt_fixed(self.f64().unwrap(), precision))
+            }
+            _ => self.cast(dtype),
+        }
+    }
+
     /// Cast from physical to logical types without any checks on the validity of the cast.
     ///
     /// # Safety
@@ -277,8 +334,9 @@ impl Series {
     }
 
     /// Compute the sum of all values in this Series.
-    /// Returns `Some(0)` if the array is empty, and `None` if the array only
-    /// contains null values.
+    /// Returns `None` if the array is empty or only contains null values, so that
+    /// it can be told apart from a sum of zero. [`Series::sum_as_series`] returns `0`
+    /// for an empty array instead.
     ///
     /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
     /// first cast to `Int64` to prevent overflow issues.
@@ -287,11 +345,16 @@ impl Series {
     /// # use polars_core::prelude::*;
     /// let s = Series::new("days", &[1, 2, 3]);
     /// assert_eq!(s.sum(), Some(6));
+    /// let s = Series::new("days", &[None::<i32>, None]);
+    /// assert_eq!(s.sum::<i32>(), None);
     /// ```
     pub fn sum<T>(&self) -> Option<T>
     where
         T: NumCast,
     {
+        if self.null_count() == self.len() {
+            return None;
+        }
         self.sum_as_series()
             .cast(&DataType::Float64)
             .ok()
@@ -332,6 +395,44 @@ impl Series {
             .and_then(|s| s.f64().unwrap().get(0).and_then(T::from))
     }
 
+    /// Returns the minimum and the maximum value, computed in a single pass over the non-null
+    /// values. Returns `None` if the dtype is not numeric or if all values are null.
+    ///
+    /// As in [`Series::min`] and [`Series::max`], `NaN` values are skipped. Both are only `NaN`
+    /// if all non-null values are.
+    pub fn min_max(&self) -> Option<(AnyValue<'static>, AnyValue<'static>)> {
+        fn min_max<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Option<(T::Native, T::Native)> {
+            let mut iter = ca
+                .downcast_iter()
+                .flat_map(|arr| arr.iter().flatten().copied());
+            let first = iter.next()?;
+            // `NaN` is the largest value for the minimum and the smallest for the maximum, so
+            // it is replaced by any other value
+            Some(iter.fold((first, first), |(min, max), v| {
+                let min = if compare_fn_nan_max(&v, &min) == Ordering::Less {
+                    v
+                } else {
+                    min
+                };
+                let max = if compare_fn_nan_min(&v, &max) == Ordering::Greater {
+                    v
+                } else {
+                    max
+                };
+                (min, max)
+            }))
+        }
+
+        let dtype = self.dtype();
+        if !(dtype.is_integer() || dtype.is_float()) {
+            return None;
+        }
+        with_match_physical_numeric_polars_type!(dtype, |$T| {
+            let ca = self.unpack::<$T>().unwrap();
+            min_max(ca).map(|(min, max)| (min.into(), max.into()))
+        })
+    }
+
     /// Explode a list or utf8 Series. This expands every item to a new row..
     pub fn explode(&self) -> PolarsResult<Series> {
         match self.dtype() {
@@ -342,41 +443,64 @@ impl Series {
     }
 
     /// Check if float value is NaN (note this is different than missing/ null)
+    ///
+    /// Integers are never NaN. Nulls stay null.
     pub fn is_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_nan()),
+            dt if dt.is_integer() => Ok(self.integer_float_mask(false)),
             _ => polars_bail!(opq = is_nan, self.dtype()),
         }
     }
 
     /// Check if float value is NaN (note this is different than missing/ null)
+    ///
+    /// Integers are never NaN. Nulls stay null.
     pub fn is_not_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_not_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_not_nan()),
+            dt if dt.is_integer() => Ok(self.integer_float_mask(true)),
             _ => polars_bail!(opq = is_not_nan, self.dtype()),
         }
     }
 
     /// Check if float value is finite
+    ///
+    /// Integers are always finite. Nulls stay null.
     pub fn is_finite(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_finite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_finite()),
+            dt if dt.is_integer() => Ok(self.integer_float_mask(true)),
             _ => polars_bail!(opq = is_finite, self.dtype()),
         }
     }
 
     /// Check if float value is infinite
+    ///
+    /// Integers are never infinite. Nulls stay null.
     pub fn is_infinite(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_infinite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_infinite()),
+            dt if dt.is_integer() => Ok(self.integer_float_mask(false)),
             _ => polars_bail!(opq = is_infinite, self.dtype()),
         }
     }
 
+    /// A mask that is `value` for the valid values of an integer [`Series`] and null for its nulls.
+    fn integer_float_mask(&self, value: bool) -> BooleanChunked {
+        let mut mask: BooleanChunked = self
+            .is_not_null()
+            .into_iter()
+            .map(|valid| valid.and_then(|valid| valid.then_some(value)))
+            .collect();
+        mask.rename(self.name());
+        mask
+    }
+
     /// Create a new ChunkedArray with values from self where the mask evaluates `true` and values
     /// from `other` where the mask evaluates `false`
     #[cfg(feature = "zip_with")]
@@ -385,6 +509,71 @@ impl Series {
         lhs.zip_with_same_type(mask, rhs.as_ref())
     }
 
+    /// Select the values of `truthy` where `mask` is `true` and those of `falsy` where it is
+    /// `false`. A null in `mask` produces a null.
+    ///
+    /// All inputs must have the same length and `truthy` and `falsy` must be coercible to a
+    /// common dtype.
+    #[cfg(feature = "zip_with")]
+    pub fn zip_with_mask(
+        mask: &BooleanChunked,
+        truthy: &Series,
+        falsy: &Series,
+    ) -> PolarsResult<Series> {
+        polars_ensure!(
+            truthy.len() == mask.len() && falsy.len() == mask.len(),
+            ShapeMismatch: "cannot zip series of lengths {} and {} with a mask of length {}",
+            truthy.len(), falsy.len(), mask.len()
+        );
+        let out = truthy.zip_with(mask, falsy)?;
+        if mask.null_count() == 0 {
+            return Ok(out);
+        }
+        let nulls = Series::full_null(out.name(), out.len(), out.dtype());
+        out.zip_with(&mask.is_not_null(), &nulls)
+    }
+
+    /// Set the values where `mask` is `false` or null to null. Existing nulls are kept.
+    ///
+    /// `mask` must have the same length as this [`Series`].
+    #[cfg(feature = "zip_with")]
+    pub fn with_validity(&self, mask: &BooleanChunked) -> PolarsResult<Series> {
+        polars_ensure!(
+            mask.len() == self.len(),
+            ShapeMismatch: "cannot mask series of length {} with a mask of length {}",
+            self.len(), mask.len()
+        );
+        if mask.all_kleene() == Some(true) {
+            return Ok(self.clone());
+        }
+        let mask = mask.fill_null_with_values(false)?;
+        let nulls = Series::full_null(self.name(), self.len(), self.dtype());
+        self.zip_with(&mask, &nulls)
+    }
+
+    /// Set the values at `indices` to null.
+    #[cfg(feature = "zip_with")]
+    pub fn set_null_at(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        let mut mask = vec![true; self.len()];
+        for &idx in indices {
+            let idx = idx as usize;
+            polars_ensure!(idx < self.len(), oob = idx, self.len());
+            mask[idx] = false;
+        }
+        self.with_validity(&BooleanChunked::from_slice("", &mask))
+    }
+
+    /// Fill the nulls of this [`Series`] with the values of `other` at the same positions.
+    ///
+    /// `other` must have the same length and dtype. See [`functions::coalesce`] to combine
+    /// more than two series.
+    ///
+    /// [`functions::coalesce`]: crate::functions::coalesce
+    #[cfg(feature = "zip_with")]
+    pub fn combine_first(&self, other: &Series) -> PolarsResult<Series> {
+        crate::functions::coalesce(&[self, other])
+    }
+
     /// Cast a datelike Series to their physical representation.
     /// Primitives remain unchanged
     ///
@@ -528,9 +717,23 @@ impl Series {
         Ok(self.finish_take_threaded(series?, rechunk))
     }
 
+    /// Compute the dot product of two numeric [`Series`].
+    ///
+    /// This is the sum of the elementwise products over the positions where both values are
+    /// not null. The [`Series`] must have the same length.
     #[cfg(feature = "dot_product")]
-    pub fn dot(&self, other: &Series) -> Option<f64> {
-        (self * other).sum::<f64>()
+    pub fn dot(&self, other: &Series) -> PolarsResult<f64> {
+        polars_ensure!(
+            self.len() == other.len(),
+            ShapeMismatch: "cannot compute the dot product of series of length {} and {}",
+            self.len(), other.len()
+        );
+        polars_ensure!(
+            self.dtype().is_numeric() && other.dtype().is_numeric(),
+            InvalidOperation: "cannot compute the dot product of series of dtype {} and {}",
+            self.dtype(), other.dtype()
+        );
+        Ok((self * other).sum::<f64>().unwrap_or(0.0))
     }
 
     /// Get the sum of the Series as a new Series of length 1.
@@ -705,6 +908,10 @@ impl Series {
         }
     }
 
+    /// Assign ranks to the values of this [`Series`], handling ties with the given [`RankMethod`].
+    ///
+    /// [`RankMethod::Average`] returns a `Float32` series, the other methods an [`IdxCa`] series.
+    /// Nulls rank as null.
     #[cfg(feature = "rank")]
     pub fn rank(&self, options: RankOptions, seed: Option<u64>) -> Series {
         rank(self, options.method, options.descending, seed)
@@ -858,6 +1065,9 @@ impl Series {
         Ok(out)
     }
     /// Get the head of the Series.
+    ///
+    /// This is a zero copy view. The length is clamped to the length of the Series
+    /// and defaults to 10.
     pub fn head(&self, length: Option<usize>) -> Series {
         match length {
             Some(len) => self.slice(0, std::cmp::min(len, self.len())),
@@ -866,6 +1076,9 @@ impl Series {
     }
 
     /// Get the tail of the Series.
+    ///
+    /// This is a zero copy view. The length is clamped to the length of the Series
+    /// and defaults to 10.
     pub fn tail(&self, length: Option<usize>) -> Series {
         let len = match length {
             Some(len) => std::cmp::min(len, self.len()),
@@ -1056,6 +1269,9 @@ mod test {
         let s2 = Series::new("b", &[3]);
         s1.append(&s2).unwrap();
         assert_eq!(s1.len(), 3);
+        // the chunks of `s2` are added, no rechunk takes place
+        assert_eq!(s1.n_chunks(), 2);
+        assert_eq!(Vec::from(s1.i32().unwrap()), &[Some(1), Some(2), Some(3)]);
 
         // add wrong type
         let s2 = Series::new("b", &[3.0]);
@@ -1079,9 +1295,283 @@ mod test {
     fn out_of_range_slice_does_not_panic() {
         let series = Series::new("a", &[1i64, 2, 3, 4, 5]);
 
-        let _ = series.slice(-3, 4);
-        let _ = series.slice(-6, 2);
-        let _ = series.slice(4, 2);
+        assert_eq!(series.slice(-3, 4).len(), 3);
+        assert_eq!(series.slice(-6, 2).len(), 2);
+        assert_eq!(series.slice(4, 2).len(), 1);
+        assert_eq!(series.slice(6, 2).len(), 0);
+        assert_eq!(series.slice(-6, 0).len(), 0);
+    }
+
+    #[test]
+    fn series_slice_chunked() {
+        let mut series = Series::new("a", &[1i64, 2, 3]);
+        series.append(&Series::new("a", &[4i64, 5, 6])).unwrap();
+
+        // the chunks that overlap the slice are kept, their data isn't copied
+        let slice = series.slice(-4, 2);
+        assert_eq!(slice.n_chunks(), 2);
+        assert_eq!(Vec::from(slice.i64().unwrap()), &[Some(3), Some(4)]);
+        let slice = series.slice(-2, 5);
+        assert_eq!(slice.n_chunks(), 1);
+        assert_eq!(Vec::from(slice.i64().unwrap()), &[Some(5), Some(6)]);
+    }
+
+    #[test]
+    fn series_head_tail() {
+        let series = Series::new("a", &[Some(1i64), None, Some(3), None, Some(5)]);
+
+        let head = series.head(Some(2));
+        assert_eq!(Vec::from(head.i64().unwrap()), &[Some(1), None]);
+        assert_eq!(head.null_count(), 1);
+
+        let tail = series.tail(Some(3));
+        assert_eq!(Vec::from(tail.i64().unwrap()), &[Some(3), None, Some(5)]);
+        assert_eq!(tail.null_count(), 1);
+
+        // `n` larger than the series is clamped to its length
+        assert_eq!(series.head(Some(10)).len(), 5);
+        assert_eq!(series.tail(Some(10)).null_count(), 2);
+        assert_eq!(series.limit(10).len(), 5);
+    }
+
+    #[test]
+    fn series_full() -> PolarsResult<()> {
+        let s = Series::full("a", AnyValue::Int64(3), 4)?;
+        assert_eq!(s.name(), "a");
+        assert_eq!(Vec::from(s.i64()?), &[Some(3); 4]);
+
+        let s = Series::full("a", AnyValue::Utf8("x"), 2)?;
+        assert_eq!(Vec::from(s.utf8()?), &[Some("x"); 2]);
+        assert_eq!(Series::full("a", AnyValue::Boolean(true), 0)?.len(), 0);
+
+        for dtype in [
+            DataType::Int32,
+            DataType::Float64,
+            DataType::Boolean,
+            DataType::Utf8,
+            DataType::List(Box::new(DataType::Int64)),
+        ] {
+            let s = Series::full_null("a", 5, &dtype);
+            assert_eq!(s.dtype(), &dtype);
+            assert_eq!(s.null_count(), 5);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn series_tile() {
+        let s = Series::new("a", &[Some(1i32), None, Some(3)]);
+        let out = s.tile(3);
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.n_chunks(), 1);
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[
+                Some(1),
+                None,
+                Some(3),
+                Some(1),
+                None,
+                Some(3),
+                Some(1),
+                None,
+                Some(3)
+            ]
+        );
+        assert!(s.tile(1).series_equal_missing(&s));
+        assert_eq!(s.tile(0).len(), 0);
+
+        let s = Series::new("b", &[Some("x"), None]);
+        assert_eq!(
+            Vec::from(s.tile(2).utf8().unwrap()),
+            &[Some("x"), None, Some("x"), None]
+        );
+
+        // multiple chunks are written into a single one
+        let mut s = Series::new("c", &[1i64, 2]);
+        s.append(&Series::new("c", &[3i64])).unwrap();
+        let out = s.tile(2);
+        assert_eq!(out.n_chunks(), 1);
+        assert_eq!(
+            Vec::from(out.i64().unwrap()),
+            &[Some(1), Some(2), Some(3), Some(1), Some(2), Some(3)]
+        );
+    }
+
+    #[test]
+    fn series_repeat_each() {
+        let s = Series::new("a", &[Some(1i32), None, Some(3)]);
+        let out = s.repeat_each(2);
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(1), Some(1), None, None, Some(3), Some(3)]
+        );
+        assert!(s.repeat_each(1).series_equal_missing(&s));
+        assert_eq!(s.repeat_each(0).len(), 0);
+
+        let s = Series::new("b", &[None, Some("x"), Some("y")]);
+        let expected = Series::new(
+            "b",
+            &[None, None, Some("x"), Some("x"), Some("y"), Some("y")],
+        );
+        assert!(s.repeat_each(2).series_equal_missing(&expected));
+
+        let mut s = Series::new("c", &[1i64, 2]);
+        s.append(&Series::new("c", &[3i64])).unwrap();
+        let out = s.repeat_each(2);
+        assert_eq!(out.n_chunks(), 1);
+        assert_eq!(
+            Vec::from(out.i64().unwrap()),
+            &[Some(1), Some(1), Some(2), Some(2), Some(3), Some(3)]
+        );
+
+        // logical types keep their dtype
+        #[cfg(feature = "dtype-date")]
+        {
+            let s = Series::new("d", &[1i32, 2]).cast(&DataType::Date).unwrap();
+            let out = s.repeat_each(2);
+            assert_eq!(out.dtype(), &DataType::Date);
+            assert_eq!(out.len(), 4);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "dot_product")]
+    fn series_dot() -> PolarsResult<()> {
+        let a = Series::new("a", &[Some(1.0), Some(2.0), None, Some(4.0)]);
+        let b = Series::new("b", &[Some(0.5), Some(3.0), Some(7.0), None]);
+        // only the first two positions are non-null in both
+        assert_eq!(a.dot(&b)?, 6.5);
+        assert_eq!(a.dot(&a)?, 21.0);
+
+        assert!(a.dot(&b.slice(0, 3)).is_err());
+        assert!(a.dot(&Series::new("c", &["a", "b", "c", "d"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "zip_with")]
+    fn series_zip_with_mask() -> PolarsResult<()> {
+        let mask = BooleanChunked::new("mask", &[Some(true), Some(false), None, Some(true)]);
+        let truthy = Series::new("a", &[1i32, 2, 3, 4]);
+        let falsy = Series::new("b", &[10.0, 20.0, 30.0, 40.0]);
+
+        let out = Series::zip_with_mask(&mask, &truthy, &falsy)?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(1.0), Some(20.0), None, Some(4.0)]
+        );
+
+        assert!(Series::zip_with_mask(&mask, &truthy, &falsy.slice(0, 3)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn series_cast_with_options() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(0.1 + 0.2), None, Some(1.0), Some(-2.005)]);
+
+        // the default representation parses back to the same floats
+        let out = s.cast_with_options(&DataType::Utf8, CastOptions::default())?;
+        assert_eq!(out.cast(&DataType::Float64)?, s);
+
+        let options = CastOptions {
+            float_precision: Some(2),
+        };
+        let out = s.cast_with_options(&DataType::Utf8, options)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("0.30"), None, Some("1.00"), Some("-2.00")]
+        );
+        let out = s
+            .cast(&DataType::Float32)?
+            .cast_with_options(&DataType::Utf8, options)?;
+        assert_eq!(Vec::from(out.utf8()?)[0], Some("0.30"));
+
+        // only floats to utf8 are affected
+        let ints = Series::new("b", &[1, 2]);
+        let out = ints.cast_with_options(&DataType::Utf8, options)?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("1"), Some("2")]);
+        Ok(())
+    }
+
+    #[test]
+    fn series_n_valid() {
+        let mut series = Series::new("a", &[Some(1i32), None, Some(3), None]);
+        assert_eq!(series.len(), 4);
+        assert_eq!(series.n_valid(), 2);
+        assert!(!series.is_empty());
+
+        series
+            .append(&Series::new("a", &[None::<i32>, Some(6)]))
+            .unwrap();
+        assert_eq!(series.n_valid(), 3);
+
+        let empty = series.slice(0, 0);
+        assert!(empty.is_empty());
+        assert_eq!(empty.n_valid(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "zip_with")]
+    fn test_with_validity() -> PolarsResult<()> {
+        let s = Series::new("a", [Some(1i32), None, Some(3), Some(4)]);
+        let mask = BooleanChunked::new("", [Some(true), Some(true), Some(false), None]);
+        let out = s.with_validity(&mask)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.null_count(), 3);
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), None, None, None]);
+
+        let out = s.set_null_at(&[0, 3])?;
+        assert_eq!(Vec::from(out.i32()?), &[None, None, Some(3), None]);
+
+        assert!(s.with_validity(&mask.slice(0, 2)).is_err());
+        assert!(s.set_null_at(&[4]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "zip_with")]
+    fn test_combine_first() -> PolarsResult<()> {
+        let s = Series::new("a", [None, Some(2i32), None, Some(4), None]);
+        let backup = Series::new("backup", [10i32, 20, 30, 40, 50]);
+        let out = s.combine_first(&backup)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(10), Some(2), Some(30), Some(4), Some(50)]
+        );
+
+        assert!(s.combine_first(&backup.slice(0, 4)).is_err());
+        assert!(s.combine_first(&backup.cast(&DataType::Int64)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_max() {
+        let s = Series::new("a", [Some(3i32), None, Some(-2), Some(7), None]);
+        assert_eq!(s.min_max(), Some((AnyValue::Int32(-2), AnyValue::Int32(7))));
+        let s = Series::new("a", [1.5f64, -0.5, 1.0]);
+        assert_eq!(
+            s.min_max(),
+            Some((AnyValue::Float64(-0.5), AnyValue::Float64(1.5)))
+        );
+        // `NaN` is skipped, also if it comes first
+        let s = Series::new("a", [f64::NAN, 2.0, -1.0]);
+        assert_eq!(
+            s.min_max(),
+            Some((AnyValue::Float64(-1.0), AnyValue::Float64(2.0)))
+        );
+        assert_eq!(s.min::<f64>(), Some(-1.0));
+        assert_eq!(s.max::<f64>(), Some(2.0));
+        let s = Series::new("a", [Some(f32::NAN), None, Some(f32::NAN)]);
+        let (min, max) = s.min_max().unwrap();
+        assert!(min.extract::<f32>().unwrap().is_nan());
+        assert!(max.extract::<f32>().unwrap().is_nan());
+        assert_eq!(Series::full_null("a", 3, &DataType::Int64).min_max(), None);
+        assert_eq!(Series::new("a", ["x", "y"]).min_max(), None);
     }
 
     #[test]
@@ -1,6 +1,122 @@
+use arrow::array::growable::make_growable;
+
 use crate::prelude::*;
+#[cfg(feature = "object")]
+use crate::utils::NoNull;
 
 impl Series {
+    /// Create a new [`Series`] that repeats `value` `length` times.
+    ///
+    /// The dtype is inferred from `value`. Use [`Series::full_null`] to create a column of
+    /// nulls with a given dtype.
+    #[doc(alias = "repeat")]
+    pub fn full(name: &str, value: AnyValue, length: usize) -> PolarsResult<Self> {
+        let s = Series::from_any_values(name, &[value], false)?;
+        Ok(s.new_from_index(0, length))
+    }
+
+    /// Repeat the whole [`Series`] `n` times, e.g. `[a, b]` tiled twice is `[a, b, a, b]`.
+    ///
+    /// Nulls are repeated along with the values. The output is a single chunk that is written
+    /// in one pass.
+    #[must_use]
+    pub fn tile(&self, n: usize) -> Self {
+        let len = self.len();
+        if n == 0 || len == 0 {
+            return self.clear();
+        }
+        #[cfg(feature = "object")]
+        if matches!(self.dtype(), DataType::Object(_)) {
+            return self.take_repeated(len * n, |i| i % len);
+        }
+        self.extend_ranges(len * n, std::iter::repeat((0, len)).take(n))
+    }
+
+    /// Repeat every value of the [`Series`] `n` times consecutively, e.g. `[a, b]` with each
+    /// value repeated twice is `[a, a, b, b]`.
+    ///
+    /// Nulls are repeated along with the values. The output is a single chunk that is written
+    /// in one pass.
+    #[must_use]
+    pub fn repeat_each(&self, n: usize) -> Self {
+        let len = self.len();
+        if n == 0 || len == 0 {
+            return self.clear();
+        }
+        #[cfg(feature = "object")]
+        if matches!(self.dtype(), DataType::Object(_)) {
+            return self.take_repeated(len * n, |i| i / n);
+        }
+
+        let physical = self.to_physical_repr();
+        let is_primitive = physical.dtype().is_numeric();
+        #[cfg(feature = "dtype-decimal")]
+        let is_primitive = is_primitive && !matches!(physical.dtype(), DataType::Decimal(_, _));
+        let mut out = if is_primitive {
+            let out = with_match_physical_numeric_polars_type!(physical.dtype(), |$T| {
+                let ca = physical.unpack::<$T>().unwrap();
+                let out: ChunkedArray<$T> = if ca.null_count() == 0 {
+                    let mut values = Vec::with_capacity(len * n);
+                    for v in ca.into_no_null_iter() {
+                        values.extend(std::iter::repeat(v).take(n));
+                    }
+                    ChunkedArray::from_vec(self.name(), values)
+                } else {
+                    ca.into_iter()
+                        .flat_map(|v| std::iter::repeat(v).take(n))
+                        .collect()
+                };
+                out.into_series()
+            });
+            // Safety:
+            // the physical dtype is unchanged
+            unsafe {
+                Series::from_chunks_and_dtype_unchecked(
+                    self.name(),
+                    out.chunks().clone(),
+                    self.dtype(),
+                )
+            }
+        } else {
+            // a range of a single value per output row, which is slower than the primitive
+            // path but doesn't allocate more than the output
+            self.extend_ranges(
+                len * n,
+                (0..len).flat_map(|i| std::iter::repeat((i, 1)).take(n)),
+            )
+        };
+        out.set_sorted_flag(self.is_sorted_flag());
+        out
+    }
+
+    /// Create a single chunk [`Series`] of `capacity` values by copying the `(start, len)`
+    /// ranges of this [`Series`] in order.
+    fn extend_ranges<I>(&self, capacity: usize, ranges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        let s = self.rechunk();
+        let arr = s.chunks()[0].as_ref();
+        let mut growable = make_growable(&[arr], false, capacity);
+        for (start, len) in ranges {
+            growable.extend(0, start, len);
+        }
+        // Safety:
+        // the array has the physical type of this Series
+        unsafe {
+            Series::from_chunks_and_dtype_unchecked(s.name(), vec![growable.as_box()], s.dtype())
+        }
+    }
+
+    /// Take `len` rows, the `i`th output row being row `f(i)` of this [`Series`].
+    #[cfg(feature = "object")]
+    fn take_repeated<F: Fn(usize) -> usize>(&self, len: usize, f: F) -> Self {
+        let idx: NoNull<IdxCa> = (0..len).map(|i| f(i) as IdxSize).collect_trusted();
+        // Safety:
+        // the indexes are in bounds
+        unsafe { self.take_unchecked(&idx.into_inner()).unwrap() }
+    }
+
     /// Extend with a constant value.
     pub fn extend_constant(&self, value: AnyValue, n: usize) -> PolarsResult<Self> {
         let s = Series::from_any_values("", &[value], false).unwrap();
@@ -11,4 +127,82 @@ impl Series {
         out.append(&to_append)?;
         Ok(out)
     }
+
+    /// Shift the values by `periods` and fill the vacated positions with `fill` instead of
+    /// nulls. A negative `periods` shifts the values to the start.
+    ///
+    /// The dtype of `fill` must match the dtype of the [`Series`]. A null `fill` is the same
+    /// as [`Series::shift`](SeriesTrait::shift).
+    pub fn shift_and_fill(&self, periods: i64, fill: AnyValue) -> PolarsResult<Self> {
+        if let AnyValue::Null = fill {
+            return Ok(self.shift(periods));
+        }
+        polars_ensure!(
+            &fill.dtype() == self.dtype(),
+            SchemaMismatch: "cannot fill a series of dtype {} with a value of dtype {}",
+            self.dtype(), fill.dtype()
+        );
+        let physical = self.to_physical_repr();
+        let is_primitive = physical.dtype().is_numeric();
+        #[cfg(feature = "dtype-decimal")]
+        let is_primitive = is_primitive && !matches!(physical.dtype(), DataType::Decimal(_, _));
+        // the fill value of a categorical is a string, not a code
+        #[cfg(feature = "dtype-categorical")]
+        let is_primitive = is_primitive && !matches!(self.dtype(), DataType::Categorical(_));
+        let out = match (physical.dtype(), &fill) {
+            (DataType::Boolean, AnyValue::Boolean(v)) => physical
+                .bool()
+                .unwrap()
+                .shift_and_fill(periods, Some(*v))
+                .into_series(),
+            (DataType::Utf8, _) => physical
+                .utf8()
+                .unwrap()
+                .shift_and_fill(periods, fill.get_str())
+                .into_series(),
+            (DataType::Binary, AnyValue::Binary(v)) => physical
+                .binary()
+                .unwrap()
+                .shift_and_fill(periods, Some(*v))
+                .into_series(),
+            (DataType::Binary, AnyValue::BinaryOwned(v)) => physical
+                .binary()
+                .unwrap()
+                .shift_and_fill(periods, Some(v.as_slice()))
+                .into_series(),
+            (DataType::List(_), AnyValue::List(v)) => physical
+                .list()
+                .unwrap()
+                .shift_and_fill(periods, Some(v))
+                .into_series(),
+            (dt, _) if is_primitive => {
+                with_match_physical_numeric_polars_type!(dt, |$T| {
+                    let ca = physical.unpack::<$T>().unwrap();
+                    ca.shift_and_fill(periods, fill.extract()).into_series()
+                })
+            }
+            _ => {
+                // dtypes without a fill kernel, e.g. structs, decimals and categoricals
+                let fill_length = std::cmp::min(periods.unsigned_abs(), self.len() as u64) as usize;
+                let mut fill = Series::full(self.name(), fill, fill_length)?;
+                return if periods < 0 {
+                    let mut out = self.slice(fill_length as i64, self.len() - fill_length);
+                    out.append(&fill)?;
+                    Ok(out)
+                } else {
+                    fill.append(&self.slice(0, self.len() - fill_length))?;
+                    Ok(fill)
+                };
+            }
+        };
+        // Safety:
+        // the physical dtype is unchanged
+        unsafe {
+            Ok(Series::from_chunks_and_dtype_unchecked(
+                self.name(),
+                out.chunks().clone(),
+                self.dtype(),
+            ))
+        }
+    }
 }
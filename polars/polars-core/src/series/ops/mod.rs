@@ -11,11 +11,21 @@ mod null;
 pub mod pct_change;
 #[cfg(feature = "round_series")]
 mod round;
+mod scatter;
 mod to_list;
 mod unique;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Options for [`Series::cast_with_options`].
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CastOptions {
+    /// Number of decimals to format floats with when casting to `Utf8`.
+    /// Defaults to `None`, the shortest representation that parses back to the same float.
+    pub float_precision: Option<usize>,
+}
+
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NullBehavior {
@@ -39,6 +39,12 @@ fn moment_precomputed_mean(s: &Series, moment: usize, mean: f64) -> PolarsResult
     Ok(out)
 }
 
+/// Whether the second central moment is zero up to the float resolution, like in scipy.
+/// The standardized moments are undefined for such (near) constant data.
+fn is_constant(m2: f64, mean: f64) -> bool {
+    m2 <= (1e-15 * mean).powi(2)
+}
+
 impl Series {
     /// Compute the sample skewness of a data set.
     ///
@@ -48,6 +54,9 @@ impl Series {
     /// function `skewtest` can be used to determine if the skewness value
     /// is close enough to zero, statistically speaking.
     ///
+    /// Returns `None` if all values are null or equal, or if `bias` is `false` and there are
+    /// fewer than 3 non-null values.
+    ///
     /// see: https://github.com/scipy/scipy/blob/47bb6febaa10658c72962b9615d5d5aa2513fa3a/scipy/stats/stats.py#L1024
     pub fn skew(&self, bias: bool) -> PolarsResult<Option<f64>> {
         let mean = match self.mean() {
@@ -56,12 +65,18 @@ impl Series {
         };
         // we can unwrap because if it were None, we already return None above
         let m2 = moment_precomputed_mean(self, 2, mean)?.unwrap();
+        if is_constant(m2, mean) {
+            return Ok(None);
+        }
         let m3 = moment_precomputed_mean(self, 3, mean)?.unwrap();
 
         let out = m3 / m2.powf(1.5);
 
         if !bias {
             let n = (self.len() - self.null_count()) as f64;
+            if n < 3.0 {
+                return Ok(None);
+            }
             Ok(Some(((n - 1.0) * n).sqrt() / (n - 2.0) * out))
         } else {
             Ok(Some(out))
@@ -76,6 +91,9 @@ impl Series {
     /// If bias is `false` then the kurtosis is calculated using k statistics to
     /// eliminate bias coming from biased moment estimators
     ///
+    /// Returns `None` if all values are null or equal, or if `bias` is `false` and there are
+    /// fewer than 4 non-null values.
+    ///
     /// see: https://github.com/scipy/scipy/blob/47bb6febaa10658c72962b9615d5d5aa2513fa3a/scipy/stats/stats.py#L1027
     pub fn kurtosis(&self, fisher: bool, bias: bool) -> PolarsResult<Option<f64>> {
         let mean = match self.mean() {
@@ -84,10 +102,16 @@ impl Series {
         };
         // we can unwrap because if it were None, we already return None above
         let m2 = moment_precomputed_mean(self, 2, mean)?.unwrap();
+        if is_constant(m2, mean) {
+            return Ok(None);
+        }
         let m4 = moment_precomputed_mean(self, 4, mean)?.unwrap();
 
         let out = if !bias {
             let n = (self.len() - self.null_count()) as f64;
+            if n < 4.0 {
+                return Ok(None);
+            }
             3.0 + 1.0 / (n - 2.0) / (n - 3.0)
                 * ((n.powf(2.0) - 1.0) * m4 / m2.powf(2.0) - 3.0 * (n - 1.0).powf(2.0))
         } else {
@@ -160,4 +184,26 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_moments_undefined() -> PolarsResult<()> {
+        let constant = Series::new("", &[Some(2.5), None, Some(2.5), Some(2.5), Some(2.5)]);
+        let all_null = Series::full_null("", 3, &DataType::Float64);
+        for s in [&constant, &all_null] {
+            assert_eq!(s.skew(true)?, None);
+            assert_eq!(s.skew(false)?, None);
+            assert_eq!(s.kurtosis(true, true)?, None);
+            assert_eq!(s.kurtosis(false, false)?, None);
+        }
+
+        // [1, 2, 4]: mean 7/3, m2 = 14/9, m3 = 20/27
+        let s = Series::new("", &[1, 2, 4]);
+        let expected = (20.0 / 27.0) / (14.0f64 / 9.0).powf(1.5);
+        assert!((s.skew(true)?.unwrap() - expected).abs() < 1e-12);
+        assert!(s.skew(false)?.is_some());
+        // the unbiased kurtosis needs 4 values
+        assert!(s.kurtosis(true, true)?.is_some());
+        assert_eq!(s.kurtosis(true, false)?, None);
+        Ok(())
+    }
 }
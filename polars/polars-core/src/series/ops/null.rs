@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 impl Series {
+    /// Create a new [`Series`] of `size` null values of type `dtype`.
     pub fn full_null(name: &str, size: usize, dtype: &DataType) -> Self {
         // match the logical types and create them
         match dtype {
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+impl Series {
+    /// Return a copy of the [`Series`] in which the value at `indices[i]` is replaced by
+    /// `values[i]`.
+    ///
+    /// `values` must have the dtype of the [`Series`] and one value per index. If an index
+    /// occurs more than once, the last of its values is used.
+    pub fn scatter(&self, indices: &[usize], values: &Series) -> PolarsResult<Series> {
+        polars_ensure!(
+            indices.len() == values.len(),
+            ShapeMismatch: "cannot scatter {} values to {} indices", values.len(), indices.len()
+        );
+        polars_ensure!(
+            values.dtype() == self.dtype(),
+            SchemaMismatch: "cannot scatter values of dtype {} into a series of dtype {}",
+            values.dtype(), self.dtype()
+        );
+        let len = self.len();
+        // take every value from `self`, except at the indices, which take from the appended
+        // `values`
+        let mut idx = (0..len as IdxSize).collect::<Vec<_>>();
+        for (i, &target) in indices.iter().enumerate() {
+            polars_ensure!(target < len, oob = target, len);
+            idx[target] = (len + i) as IdxSize;
+        }
+        let mut out = self.clone();
+        out.append(values)?;
+        out.take(&IdxCa::from_vec("", idx))
+    }
+}
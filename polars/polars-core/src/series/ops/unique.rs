@@ -27,6 +27,8 @@ where
 
 impl Series {
     /// Returns a count of the unique values in the order of appearance.
+    ///
+    /// The counts are aligned with the values returned by [`Series::unique_stable`].
     #[cfg(feature = "unique_counts")]
     pub fn unique_counts(&self) -> IdxCa {
         if self.dtype().to_physical().is_numeric() {
@@ -39,7 +41,9 @@ impl Series {
             }
         } else {
             match self.dtype() {
+                DataType::Boolean => unique_counts(self.bool().unwrap().into_iter()),
                 DataType::Utf8 => unique_counts(self.utf8().unwrap().into_iter()),
+                DataType::Binary => unique_counts(self.binary().unwrap().into_iter()),
                 dt => {
                     panic!("'unique_counts' not implemented for {dt} data types")
                 }
@@ -235,7 +235,9 @@ pub trait SeriesTrait:
     /// Get a zero copy view of the data.
     ///
     /// When offset is negative the offset is counted from the
-    /// end of the array
+    /// end of the array. An out of bounds slice is clamped to the array: an offset past
+    /// the end gives an empty [`Series`], a negative offset before the start begins at the
+    /// start.
     fn slice(&self, _offset: i64, _length: usize) -> Series;
 
     #[doc(hidden)]
@@ -519,6 +521,9 @@ pub trait SeriesTrait:
 
     #[cfg(feature = "mode")]
     /// Compute the most occurring element in the array.
+    ///
+    /// All tied elements are returned, in order of their first occurrence. Null counts as an
+    /// element.
     fn mode(&self) -> PolarsResult<Series> {
         polars_bail!(opq = mode, self._dtype());
     }
@@ -2,6 +2,7 @@
 use std::ops::Deref;
 
 use crate::prelude::*;
+use crate::utils::get_supertype;
 
 impl Series {
     /// Check if series are equal. Note that `None == None` evaluates to `false`
@@ -37,13 +38,39 @@ impl Series {
         self.len() == other.len()
             && self.name() == other.name()
             && self.null_count() == other.null_count()
-            && {
-                let eq = self.equal(other);
-                match eq {
-                    Ok(b) => b.sum().map(|s| s as usize).unwrap_or(0) == self.len(),
-                    Err(_) => false,
-                }
+            && self.values_equal_by_chunk(other)
+    }
+
+    /// Compare the values of two series of equal length, where `None == None` evaluates
+    /// to `true`.
+    ///
+    /// The series are compared in parts that lie within a single chunk of both, so series with
+    /// different chunk layouts are compared without a rechunk.
+    fn values_equal_by_chunk(&self, other: &Series) -> bool {
+        let chunk_ends = |s: &Series| {
+            s.chunk_lengths()
+                .scan(0, |end, len| {
+                    *end += len;
+                    Some(*end)
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut ends = chunk_ends(self);
+        ends.extend(chunk_ends(other));
+        ends.sort_unstable();
+        ends.dedup();
+
+        let mut start = 0;
+        ends.into_iter().all(|end| {
+            let len = end - start;
+            let lhs = self.slice(start as i64, len);
+            let rhs = other.slice(start as i64, len);
+            start = end;
+            match lhs.equal(&rhs) {
+                Ok(b) => b.sum().map(|s| s as usize).unwrap_or(0) == len,
+                Err(_) => false,
             }
+        })
     }
 
     /// Get a pointer to the underlying data of this Series.
@@ -97,6 +124,8 @@ impl DataFrame {
 
     /// Check if `DataFrames` are equal. Note that `None == None` evaluates to `false`
     ///
+    /// Only the values are compared, not how the columns are split into chunks.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -147,6 +176,48 @@ impl DataFrame {
         true
     }
 
+    /// Check if all values in `DataFrames` are equal where `None == None` evaluates to `true`,
+    /// ignoring differences between numeric dtypes.
+    ///
+    /// Numeric columns of different dtypes, e.g. `Int32` and `Int64`, are cast to their
+    /// supertype before their values are compared. Columns of other dtypes must have identical
+    /// dtypes. Use [`DataFrame::frame_equal_missing`] to require identical dtypes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df1: DataFrame = df!("a" => &[1i32, 2, 3])?;
+    /// let df2: DataFrame = df!("a" => &[1i64, 2, 3])?;
+    ///
+    /// assert!(!df1.frame_equal_missing(&df2));
+    /// assert!(df1.frame_equal_loose(&df2));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn frame_equal_loose(&self, other: &DataFrame) -> bool {
+        if self.shape() != other.shape() {
+            return false;
+        }
+        self.get_columns()
+            .iter()
+            .zip(other.get_columns())
+            .all(|(left, right)| {
+                if left.dtype() == right.dtype() {
+                    return left.series_equal_missing(right);
+                }
+                if !left.dtype().is_numeric() || !right.dtype().is_numeric() {
+                    return false;
+                }
+                match get_supertype(left.dtype(), right.dtype()) {
+                    Some(dtype) => match (left.cast(&dtype), right.cast(&dtype)) {
+                        (Ok(left), Ok(right)) => left.series_equal_missing(&right),
+                        _ => false,
+                    },
+                    None => false,
+                }
+            })
+    }
+
     /// Checks if the Arc ptrs of the Series are equal
     ///
     /// # Example
@@ -210,6 +281,51 @@ mod test {
         assert!(df1.frame_equal(&df2))
     }
 
+    #[test]
+    fn test_df_equal_chunked() {
+        let df1 = df!("a" => &[Some(1), None, Some(3), Some(4)],
+                      "b" => &["x", "y", "z", "w"])
+        .unwrap();
+        let mut df2 = df1.slice(0, 1);
+        df2.vstack_mut(&df1.slice(1, 3)).unwrap();
+        let mut df3 = df1.slice(0, 3);
+        df3.vstack_mut(&df1.slice(3, 1)).unwrap();
+        assert_eq!(df2.n_chunks(), 2);
+
+        assert!(df1.frame_equal_missing(&df2));
+        assert!(df2.frame_equal_missing(&df3));
+        // the frames are not rechunked
+        assert_eq!(df2.n_chunks(), 2);
+
+        let mut df4 = df1.slice(0, 2);
+        df4.vstack_mut(&df1.slice(0, 2)).unwrap();
+        assert!(!df2.frame_equal_missing(&df4));
+    }
+
+    #[test]
+    fn test_df_equal_loose() {
+        let df1 = df!("a" => &[Some(1i32), None, Some(3)],
+                      "b" => &["x", "y", "z"])
+        .unwrap();
+        let df2 = df!("a" => &[Some(1i64), None, Some(3)],
+                      "b" => &["x", "y", "z"])
+        .unwrap();
+        let df3 = df!("a" => &[Some(1i64), None, Some(4)],
+                      "b" => &["x", "y", "z"])
+        .unwrap();
+
+        assert!(df1.frame_equal_loose(&df1));
+        assert!(!df1.frame_equal_missing(&df2));
+        assert!(df1.frame_equal_loose(&df2));
+        assert!(!df1.frame_equal_loose(&df3));
+
+        // only numeric dtypes are upcast
+        let df4 = df!("a" => &[Some(1i32), None, Some(3)],
+                      "b" => &[Some(true), Some(false), None])
+        .unwrap();
+        assert!(!df1.frame_equal_loose(&df4));
+    }
+
     #[test]
     fn test_series_partialeq() {
         let s1 = Series::new("a", &[1_i32, 2_i32, 3_i32]);
@@ -51,6 +51,8 @@ pub struct JoinOptions {
     /// Holds `(Option<known_size>, estimated_size)`
     pub rows_left: (Option<usize>, usize),
     pub rows_right: (Option<usize>, usize),
+    /// The number of rows the join is expected to produce. Only used to pre-allocate.
+    pub expected_size: Option<usize>,
 }
 
 impl Default for JoinOptions {
@@ -63,6 +65,7 @@ impl Default for JoinOptions {
             slice: None,
             rows_left: (None, usize::MAX),
             rows_right: (None, usize::MAX),
+            expected_size: None,
         }
     }
 }
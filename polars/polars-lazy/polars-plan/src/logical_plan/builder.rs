@@ -683,6 +683,15 @@ impl LogicalPlanBuilder {
             }
         }
 
+        #[cfg(feature = "cross_join")]
+        if matches!(options.how, JoinType::Cross) && !(left_on.is_empty() && right_on.is_empty()) {
+            return LogicalPlan::Error {
+                input: Box::new(self.0),
+                err: polars_err!(InvalidOperation: "a cross join doesn't take join keys").into(),
+            }
+            .into();
+        }
+
         let schema_left = try_delayed!(self.0.schema(), &self.0, into);
         let schema_right = try_delayed!(other.schema(), &self.0, into);
 
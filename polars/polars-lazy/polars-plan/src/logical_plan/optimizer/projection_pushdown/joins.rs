@@ -1,6 +1,8 @@
 #![allow(clippy::too_many_arguments)]
 use std::collections::BTreeSet;
 
+use polars_core::frame::hash_join::_join_suffix_name_original;
+
 use super::*;
 
 fn add_keys_to_accumulated_state(
@@ -365,12 +367,9 @@ fn process_projection(
 
         let suffix = options.suffix.as_ref();
         // If _right suffix exists we need to push a projection down without this
-        // suffix.
-        if leaf_column_name.ends_with(suffix) {
+        // suffix. Chained joins may have disambiguated it further, e.g. "foo_right_1".
+        if let Some(downwards_name) = _join_suffix_name_original(&leaf_column_name, suffix) {
             // downwards name is the name without the _right i.e. "foo".
-            let (downwards_name, _) =
-                leaf_column_name.split_at(leaf_column_name.len() - suffix.len());
-
             let downwards_name_column = expr_arena.add(AExpr::Column(Arc::from(downwards_name)));
             // project downwards and locally immediately alias to prevent wrong projections
             if names_right.insert(Arc::from(downwards_name)) {
@@ -448,9 +447,10 @@ fn resolve_join_suffixes(
 
     for proj in local_projection {
         for name in aexpr_to_leaf_names(*proj, expr_arena) {
-            if name.contains(suffix.as_ref()) && schema_after_join.get(&name).is_none() {
-                let new_name = &name.as_ref()[..name.len() - suffix.len()];
-
+            if schema_after_join.get(&name).is_some() {
+                continue;
+            }
+            if let Some(new_name) = _join_suffix_name_original(&name, suffix.as_ref()) {
                 let renamed = aexpr_assign_renamed_leaf(*proj, expr_arena, &name, new_name);
 
                 let aliased = expr_arena.add(AExpr::Alias(renamed, name));
@@ -1,7 +1,6 @@
 use std::borrow::Cow;
 
 use polars_core::prelude::*;
-use polars_utils::format_smartstring;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -238,6 +237,16 @@ pub(crate) fn det_join_schema(
                 PlHashSet::with_capacity(schema_left.len() + schema_right.len());
             let mut new_schema = Schema::with_capacity(schema_left.len() + schema_right.len());
 
+            // outer joins output the coalesced key columns first
+            if matches!(options.how, JoinType::Outer) {
+                let mut arena = Arena::with_capacity(8);
+                for e in left_on {
+                    let field = e.to_field_amortized(schema_left, Context::Default, &mut arena)?;
+                    new_schema.with_column(field.name, field.dtype);
+                    arena.clear();
+                }
+            }
+
             for (name, dtype) in schema_left.iter() {
                 names.insert(name.as_str());
                 new_schema.with_column(name.clone(), dtype.clone());
@@ -301,8 +310,14 @@ pub(crate) fn det_join_schema(
                             }
                         }
 
-                        let new_name = format_smartstring!("{}{}", name, options.suffix.as_ref());
-                        new_schema.with_column(new_name, dtype.clone());
+                        use polars_core::frame::hash_join::_join_suffix_name_unique;
+                        let new_name =
+                            _join_suffix_name_unique(name, options.suffix.as_ref(), |candidate| {
+                                new_schema.contains(candidate)
+                                    || (schema_right.contains(candidate)
+                                        && !right_names.contains(candidate))
+                            });
+                        new_schema.with_column(new_name.into(), dtype.clone());
                     } else {
                         new_schema.with_column(name.clone(), dtype.clone());
                     }
@@ -1341,6 +1341,7 @@ pub struct JoinBuilder {
     allow_parallel: bool,
     force_parallel: bool,
     suffix: Option<String>,
+    expected_size: Option<usize>,
 }
 impl JoinBuilder {
     pub fn new(lf: LazyFrame) -> Self {
@@ -1353,6 +1354,7 @@ impl JoinBuilder {
             allow_parallel: true,
             force_parallel: false,
             suffix: None,
+            expected_size: None,
         }
     }
 
@@ -1406,6 +1408,15 @@ impl JoinBuilder {
         self
     }
 
+    /// The number of rows the join is expected to produce.
+    ///
+    /// Inner and left joins on a single key reserve memory for this many join tuples up
+    /// front. This only affects the allocation, not the output.
+    pub fn expected_size(mut self, expected_size: usize) -> Self {
+        self.expected_size = Some(expected_size);
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
@@ -1431,6 +1442,7 @@ impl JoinBuilder {
                     force_parallel: self.force_parallel,
                     how: self.how,
                     suffix,
+                    expected_size: self.expected_size,
                     ..Default::default()
                 },
             )
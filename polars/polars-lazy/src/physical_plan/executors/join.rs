@@ -9,6 +9,7 @@ pub struct JoinExec {
     parallel: bool,
     suffix: Cow<'static, str>,
     slice: Option<(i64, usize)>,
+    expected_size: Option<usize>,
 }
 
 impl JoinExec {
@@ -22,6 +23,7 @@ impl JoinExec {
         parallel: bool,
         suffix: Cow<'static, str>,
         slice: Option<(i64, usize)>,
+        expected_size: Option<usize>,
     ) -> Self {
         JoinExec {
             input_left: Some(input_left),
@@ -32,6 +34,7 @@ impl JoinExec {
             parallel,
             suffix,
             slice,
+            expected_size,
         }
     }
 }
@@ -148,13 +151,15 @@ impl Executor for JoinExec {
                 }
             }
 
+            let mut args = JoinArgs::new(self.how.clone())
+                .with_suffix(Some(self.suffix.clone().into_owned()))
+                .with_expected_size(self.expected_size);
+            args.slice = self.slice;
             let df = df_left._join_impl(
                 &df_right,
                 left_on_series,
                 right_on_series,
-                self.how.clone(),
-                Some(self.suffix.clone().into_owned()),
-                self.slice,
+                args,
                 true,
                 state.verbose(),
             );
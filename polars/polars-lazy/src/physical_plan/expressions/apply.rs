@@ -448,7 +448,7 @@ impl ApplyExpr {
                         // don't need to read
                         if ChunkCompare::<&Series>::lt(input, &min)
                             .ok()
-                            .map(|ca| ca.all())
+                            .and_then(|ca| ca.all_kleene())
                             == Some(true)
                         {
                             return Ok(false);
@@ -458,7 +458,7 @@ impl ApplyExpr {
                         // don't need to read
                         if ChunkCompare::<&Series>::gt(input, &max)
                             .ok()
-                            .map(|ca| ca.all())
+                            .and_then(|ca| ca.all_kleene())
                             == Some(true)
                         {
                             return Ok(false);
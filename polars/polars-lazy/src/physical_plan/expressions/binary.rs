@@ -299,7 +299,7 @@ mod stats {
         // literal is greater than max, don't need to read
         if ChunkCompare::<&Series>::gt(literal, min_max)
             .ok()
-            .map(|s| s.all())
+            .and_then(|s| s.all_kleene())
             == Some(true)
         {
             return false;
@@ -308,7 +308,7 @@ mod stats {
         // literal is smaller than min, don't need to read
         if ChunkCompare::<&Series>::lt(literal, min_max)
             .ok()
-            .map(|s| s.all())
+            .and_then(|s| s.all_kleene())
             == Some(true)
         {
             return false;
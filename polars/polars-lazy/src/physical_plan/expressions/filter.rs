@@ -70,7 +70,8 @@ impl PhysicalExpr for FilterExpr {
             let predicate = predicate_s.bool()?;
 
             // all values true don't do anything
-            if predicate.all() {
+            // nulls are filtered out, so they must be counted here
+            if predicate.all_kleene() == Some(true) {
                 return Ok(ac_s);
             }
             // all values false
@@ -474,6 +474,7 @@ pub fn create_physical_plan(
                 parallel,
                 options.suffix,
                 options.slice,
+                options.expected_size,
             )))
         }
         HStack { input, exprs, .. } => {
@@ -34,6 +34,46 @@ fn test_join_suffix_and_drop() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_project_chained_join_suffix() -> PolarsResult<()> {
+    let frame = |rain: [f64; 3]| -> PolarsResult<LazyFrame> {
+        Ok(df![
+            "days" => [0, 1, 2],
+            "rain" => rain
+        ]?
+        .lazy())
+    };
+    let q = frame([0.1, 0.2, 0.3])?
+        .left_join(frame([0.4, 0.5, 0.6])?, "days", "days")
+        .left_join(frame([0.7, 0.8, 0.9])?, "days", "days");
+
+    let out = q.clone().collect()?;
+    assert_eq!(
+        out.get_column_names(),
+        &["days", "rain", "rain_right", "rain_right_1"]
+    );
+
+    // the suffixed names are pushed down as "rain" into the right inputs
+    let out = q.clone().select([col("rain_right_1")]).collect()?;
+    assert_eq!(
+        Vec::from(out.column("rain_right_1")?.f64()?),
+        &[Some(0.7), Some(0.8), Some(0.9)]
+    );
+    let out = q
+        .select([col("rain_right"), col("rain_right_1").alias("last")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["rain_right", "last"]);
+    assert_eq!(
+        Vec::from(out.column("rain_right")?.f64()?),
+        &[Some(0.4), Some(0.5), Some(0.6)]
+    );
+    assert_eq!(
+        Vec::from(out.column("last")?.f64()?),
+        &[Some(0.7), Some(0.8), Some(0.9)]
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "cross_join")]
 fn test_cross_join_pd() -> PolarsResult<()> {
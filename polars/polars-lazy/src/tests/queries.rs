@@ -1154,8 +1154,23 @@ fn test_cross_join() -> PolarsResult<()> {
         "b" => [None, Some(12)]
     ]?;
 
-    let out = df1.lazy().cross_join(df2.lazy()).collect()?;
+    let out = df1
+        .clone()
+        .lazy()
+        .cross_join(df2.clone().lazy())
+        .collect()?;
     assert_eq!(out.shape(), (6, 4));
+
+    // a cross join doesn't take keys
+    let out = df1
+        .lazy()
+        .join_builder()
+        .with(df2.lazy())
+        .on([col("a")])
+        .how(JoinType::Cross)
+        .finish()
+        .collect();
+    assert!(out.is_err());
     Ok(())
 }
 
@@ -287,6 +287,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_interpolate_series_method() {
+        use crate::series::SeriesMethods;
+
+        let s = Series::new("", &[Some(1.0), None, None, Some(4.0)]);
+        let out = s.interpolate(InterpolationMethod::Linear);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)]
+        );
+
+        let s = Series::new("", &[None, Some(1.0), None, Some(4.0), None]);
+        let out = s.interpolate(InterpolationMethod::Nearest);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[None, Some(1.0), Some(4.0), Some(4.0), None]
+        );
+    }
+
     #[test]
     fn test_interpolate_decreasing_unsigned() {
         let ca = UInt32Chunked::new("", &[Some(4), None, None, Some(1)]);
@@ -5,6 +5,7 @@ use std::borrow::Cow;
 
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
+use polars_core::export::ahash;
 use polars_core::frame::hash_join::*;
 use polars_core::prelude::*;
 use polars_core::utils::{_to_physical_and_bit_repr, slice_slice};
@@ -72,47 +73,235 @@ pub trait DataFrameJoinOps: IntoDf {
         how: JoinType,
         suffix: Option<String>,
     ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.join_with_args(
+            other,
+            left_on,
+            right_on,
+            JoinArgs::new(how).with_suffix(suffix),
+        )
+    }
+
+    /// Generic join method that takes [`JoinArgs`] to further configure the join.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     // emit the rows in the order of the left frame
+    ///     let args = JoinArgs::new(JoinType::Inner).with_maintain_order(true);
+    ///     left.join_with_args(right, ["join_column_left"], ["join_column_right"], args)
+    /// }
+    /// ```
+    fn join_with_args<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        args: JoinArgs,
+    ) -> PolarsResult<DataFrame>
     where
         I: IntoIterator<Item = S>,
         S: AsRef<str>,
     {
         let df_left = self.to_df();
+        let selected_left = df_left.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
         #[cfg(feature = "cross_join")]
-        if let JoinType::Cross = how {
-            return df_left.cross_join(other, suffix.as_deref(), None);
+        if let JoinType::Cross = args.how {
+            // every row is combined with every row, so keys would have no effect
+            polars_ensure!(
+                selected_left.is_empty() && selected_right.is_empty(),
+                InvalidOperation: "a cross join doesn't take join keys"
+            );
+            return df_left.cross_join_with_max_rows(
+                other,
+                args.suffix.as_deref(),
+                args.slice,
+                args.cross_join_max_rows,
+            );
         }
-        let selected_left = df_left.select_series(left_on)?;
+        self._join_impl(other, selected_left, selected_right, args, true, false)
+    }
+
+    /// Compute the row indices of a join without materializing the joined [`DataFrame`].
+    ///
+    /// Returns the indices into this frame and into `other`, named `"left"` and `"right"`.
+    /// Rows without a match, e.g. in a left or outer join, get a null index. Only inner, left
+    /// and outer joins are supported. Of the `args`, only the join type, `maintain_order`,
+    /// `hash_seed`, `slice`, `validation`, `explosion_factor` and `join_nulls` are used.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     let args = JoinArgs::new(JoinType::Inner);
+    ///     let (idx_left, idx_right) = left.join_indices(right, ["key"], ["key"], args)?;
+    ///     left.take(&idx_left)?.hstack(right.drop("key")?.take(&idx_right)?.get_columns())
+    /// }
+    /// ```
+    fn join_indices<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        args: JoinArgs,
+    ) -> PolarsResult<(IdxCa, IdxCa)>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let selected_left = self.to_df().select_series(left_on)?;
         let selected_right = other.select_series(right_on)?;
-        self._join_impl(
-            other,
-            selected_left,
-            selected_right,
-            how,
-            suffix,
-            None,
-            true,
-            false,
-        )
+        join_indices_from_series(&selected_left, &selected_right, &args)
     }
 
     #[doc(hidden)]
-    #[allow(clippy::too_many_arguments)]
     fn _join_impl(
         &self,
         other: &DataFrame,
         selected_left: Vec<Series>,
         selected_right: Vec<Series>,
-        how: JoinType,
-        suffix: Option<String>,
-        slice: Option<(i64, usize)>,
+        args: JoinArgs,
         _check_rechunk: bool,
         _verbose: bool,
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
+        if args.drop_keys {
+            // the left keys, and the right keys that are kept, e.g. by asof joins
+            let keys = selected_left
+                .iter()
+                .map(|s| s.name().to_string())
+                .chain(
+                    selected_right
+                        .iter()
+                        .filter(|s| left_df.find_idx_by_name(s.name()).is_none())
+                        .map(|s| s.name().to_string()),
+                )
+                .collect::<PlHashSet<_>>();
+            // the keys are dropped, so there is nothing to coalesce
+            let out = self._join_impl(
+                other,
+                selected_left,
+                selected_right,
+                args.with_drop_keys(false).with_coalesce_key(true),
+                _check_rechunk,
+                _verbose,
+            )?;
+            let keys = out
+                .get_column_names()
+                .into_iter()
+                .filter(|name| keys.contains(*name))
+                .collect::<Vec<_>>();
+            return Ok(out.drop_many(&keys));
+        }
+        let how = args.how.clone();
+        let suffix = args.suffix.clone();
+        let slice = args.slice;
 
         #[cfg(feature = "cross_join")]
         if let JoinType::Cross = how {
-            return left_df.cross_join(other, suffix.as_deref(), slice);
+            return left_df.cross_join_with_max_rows(
+                other,
+                suffix.as_deref(),
+                slice,
+                args.cross_join_max_rows,
+            );
+        }
+
+        if let Some(value) = &args.null_key_fill {
+            let (left_df, selected_left) = fill_null_keys(left_df, &selected_left, value)?;
+            let (other, selected_right) = fill_null_keys(other, &selected_right, value)?;
+            let mut args = args;
+            args.null_key_fill = None;
+            return left_df._join_impl(
+                &other,
+                selected_left,
+                selected_right,
+                args,
+                _check_rechunk,
+                _verbose,
+            );
+        }
+
+        if let Some(key_map) = &args.key_map {
+            return join_on_mapped_keys(
+                left_df,
+                other,
+                &selected_left,
+                &selected_right,
+                key_map,
+                &args,
+            );
+        }
+
+        let drop_right_nulls = !args.join_nulls
+            && match how {
+                JoinType::Inner | JoinType::Left => true,
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi | JoinType::Anti => true,
+                _ => false,
+            };
+        if drop_right_nulls {
+            // a right row with a null key matches nothing, so it doesn't affect the output
+            // outer joins have to keep these rows, they unmatch them from the join tuples
+            if let Some(mask) = _join_keys_not_null(&selected_right) {
+                let other = other.filter(&mask)?;
+                let selected_right = selected_right
+                    .iter()
+                    .map(|s| s.filter(&mask))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                return self._join_impl(
+                    &other,
+                    selected_left,
+                    selected_right,
+                    args,
+                    _check_rechunk,
+                    _verbose,
+                );
+            }
+        }
+
+        #[cfg(feature = "dtype-categorical")]
+        if selected_left
+            .iter()
+            .zip(&selected_right)
+            .any(|(l, r)| _check_categorical_src(l.dtype(), r.dtype()).is_err())
+        {
+            // the codes of categoricals from different sources don't correspond, so we
+            // re-encode both sides with a shared dictionary
+            let (left_df, other, selected_left, selected_right) =
+                unify_categorical_keys(left_df, other, selected_left, selected_right)?;
+            return left_df._join_impl(
+                &other,
+                selected_left,
+                selected_right,
+                args,
+                _check_rechunk,
+                _verbose,
+            );
+        }
+
+        if !args.coalesce_key && matches!(how, JoinType::Inner | JoinType::Left | JoinType::Outer) {
+            // keep all columns of both frames, `_finish_join` suffixes the colliding right keys
+            let (idx_left, idx_right) =
+                join_indices_from_series(&selected_left, &selected_right, &args)?;
+            let left_names = _join_left_names(left_df)?;
+            let (df_left, df_right) =
+                POOL.join(|| left_df.take(&idx_left), || other.take(&idx_right));
+            return _finish_join_with_left_names(
+                df_left?,
+                df_right?,
+                suffix.as_deref(),
+                &left_names,
+            );
         }
 
         #[cfg(feature = "chunked_ids")]
@@ -147,46 +336,58 @@ pub trait DataFrameJoinOps: IntoDf {
                     &right,
                     selected_left,
                     selected_right,
-                    how,
-                    suffix,
-                    slice,
+                    args,
                     false,
                     _verbose,
                 );
             }
         }
 
-        polars_ensure!(
-            selected_left.len() == selected_right.len(),
-            ComputeError: "the number of columns given as join key should be equal"
-        );
-        polars_ensure!(
-            selected_left
-            .iter()
-            .zip(&selected_right)
-            .all(|(l, r)| l.dtype() == r.dtype()),
-            ComputeError: "datatypes of join keys don't match"
-        );
+        check_join_keys(&selected_left, &selected_right)?;
 
         #[cfg(feature = "dtype-categorical")]
         for (l, r) in selected_left.iter().zip(&selected_right) {
             _check_categorical_src(l.dtype(), r.dtype())?
         }
+        args.validation.validate(&selected_left, &selected_right)?;
 
         // Single keys
         if selected_left.len() == 1 {
             let s_left = left_df.column(selected_left[0].name())?;
             let s_right = other.column(selected_right[0].name())?;
             return match how {
-                JoinType::Inner => {
-                    left_df._inner_join_from_series(other, s_left, s_right, suffix, slice, _verbose)
-                }
-                JoinType::Left => {
-                    left_df._left_join_from_series(other, s_left, s_right, suffix, slice, _verbose)
-                }
-                JoinType::Outer => {
-                    left_df._outer_join_from_series(other, s_left, s_right, suffix, slice)
-                }
+                JoinType::Inner => left_df._inner_join_from_series(
+                    other,
+                    s_left,
+                    s_right,
+                    suffix,
+                    slice,
+                    args.maintain_order,
+                    _verbose,
+                    args.explosion_factor,
+                    args.expected_size,
+                ),
+                JoinType::Left => left_df._left_join_from_series(
+                    other,
+                    s_left,
+                    s_right,
+                    suffix,
+                    slice,
+                    _verbose,
+                    args.explosion_factor,
+                    args.expected_size,
+                    args.reverse_matches,
+                ),
+                JoinType::Outer => left_df._outer_join_from_series(
+                    other,
+                    s_left,
+                    s_right,
+                    suffix,
+                    slice,
+                    args.hash_seed,
+                    args.explosion_factor,
+                    args.join_nulls,
+                ),
                 #[cfg(feature = "semi_anti_join")]
                 JoinType::Anti => left_df._semi_anti_join_from_series(s_left, s_right, slice, true),
                 #[cfg(feature = "semi_anti_join")]
@@ -219,9 +420,9 @@ pub trait DataFrameJoinOps: IntoDf {
                             suffix,
                             slice,
                         ),
-                        _ => {
-                            panic!("expected by arguments on both sides")
-                        }
+                        _ => polars_bail!(
+                            InvalidOperation: "expected asof join `by` arguments on both sides"
+                        ),
                     }
                 }
                 JoinType::Cross => {
@@ -230,16 +431,6 @@ pub trait DataFrameJoinOps: IntoDf {
             };
         }
 
-        fn remove_selected(df: &DataFrame, selected: &[Series]) -> DataFrame {
-            let mut new = None;
-            for s in selected {
-                new = match new {
-                    None => Some(df.drop(s.name()).unwrap()),
-                    Some(new) => Some(new.drop(s.name()).unwrap()),
-                }
-            }
-            new.unwrap()
-        }
         // make sure that we don't have logical types.
         // we don't overwrite the original selected as that might be used to create a column in the new df
         let selected_left_physical = _to_physical_and_bit_repr(&selected_left);
@@ -248,11 +439,30 @@ pub trait DataFrameJoinOps: IntoDf {
         // multiple keys
         match how {
             JoinType::Inner => {
+                let left_names = _join_left_names(left_df)?;
+                if left_df.height() == 0 || other.height() == 0 {
+                    return _finish_join_with_left_names(
+                        left_df.clear(),
+                        remove_selected(other, &selected_right).clear(),
+                        suffix.as_deref(),
+                        &left_names,
+                    );
+                }
                 let left = DataFrame::new_no_checks(selected_left_physical);
                 let right = DataFrame::new_no_checks(selected_right_physical);
                 let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
-                let (join_idx_left, join_idx_right) =
-                    _inner_join_multiple_keys(&mut left, &mut right, swap);
+                let (mut join_idx_left, mut join_idx_right) =
+                    _inner_join_multiple_keys(&mut left, &mut right, swap, args.hash_seed);
+                _check_join_explosion(
+                    args.explosion_factor,
+                    join_idx_left.len(),
+                    left_df.height(),
+                    other.height(),
+                )?;
+                if args.maintain_order {
+                    _sort_inner_join_tuples(&mut join_idx_left, &mut join_idx_right);
+                }
+                let sorted = !swap || args.maintain_order;
                 let mut join_idx_left = &*join_idx_left;
                 let mut join_idx_right = &*join_idx_right;
 
@@ -263,33 +473,56 @@ pub trait DataFrameJoinOps: IntoDf {
 
                 let (df_left, df_right) = POOL.join(
                     // safety: join indices are known to be in bounds
-                    || unsafe { left_df._create_left_df_from_slice(join_idx_left, false, !swap) },
+                    || unsafe { left_df._create_left_df_from_slice(join_idx_left, false, sorted) },
                     || unsafe {
                         // remove join columns
                         remove_selected(other, &selected_right)
                             ._take_unchecked_slice(join_idx_right, true)
                     },
                 );
-                _finish_join(df_left, df_right, suffix.as_deref())
+                _finish_join_with_left_names(df_left, df_right, suffix.as_deref(), &left_names)
             }
             JoinType::Left => {
                 let mut left = DataFrame::new_no_checks(selected_left_physical);
                 let mut right = DataFrame::new_no_checks(selected_right_physical);
-                let ids = _left_join_multiple_keys(&mut left, &mut right, None, None);
+                let ids = if left.height() == 0 || right.height() == 0 {
+                    _left_join_ids_no_matches(left.height())
+                } else {
+                    _left_join_multiple_keys(&mut left, &mut right, None, None, args.hash_seed)
+                };
 
                 left_df._finish_left_join(
                     ids,
                     &remove_selected(other, &selected_right),
                     suffix,
                     slice,
+                    args.explosion_factor,
+                    args.reverse_matches,
                 )
             }
             JoinType::Outer => {
                 let left = DataFrame::new_no_checks(selected_left_physical);
                 let right = DataFrame::new_no_checks(selected_right_physical);
 
-                let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
-                let opt_join_tuples = _outer_join_multiple_keys(&mut left, &mut right, swap);
+                let mut opt_join_tuples = if left.height() == 0 || right.height() == 0 {
+                    _outer_join_tuples_no_matches(left.height(), right.height())
+                } else {
+                    let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
+                    _outer_join_multiple_keys(&mut left, &mut right, swap, args.hash_seed)
+                };
+                if !args.join_nulls {
+                    opt_join_tuples = _outer_join_tuples_unmatch_nulls(
+                        opt_join_tuples,
+                        &selected_left,
+                        &selected_right,
+                    );
+                }
+                _check_join_explosion(
+                    args.explosion_factor,
+                    opt_join_tuples.len(),
+                    left_df.height(),
+                    other.height(),
+                )?;
 
                 let mut opt_join_tuples = &*opt_join_tuples;
 
@@ -334,10 +567,13 @@ pub trait DataFrameJoinOps: IntoDf {
                 let mut left = DataFrame::new_no_checks(selected_left_physical);
                 let mut right = DataFrame::new_no_checks(selected_right_physical);
 
-                let idx = if matches!(how, JoinType::Anti) {
-                    _left_anti_multiple_keys(&mut left, &mut right)
+                let anti = matches!(how, JoinType::Anti);
+                let idx = if left.height() == 0 || right.height() == 0 {
+                    _semi_anti_join_ids_no_matches(left.height(), anti)
+                } else if anti {
+                    _left_anti_multiple_keys(&mut left, &mut right, args.hash_seed)
                 } else {
-                    _left_semi_multiple_keys(&mut left, &mut right)
+                    _left_semi_multiple_keys(&mut left, &mut right, args.hash_seed)
                 };
                 // Safety:
                 // indices are in bounds
@@ -373,6 +609,10 @@ pub trait DataFrameJoinOps: IntoDf {
     }
 
     /// Perform a left join on two DataFrames
+    ///
+    /// The output rows follow the row order of the left DataFrame. If a left row has
+    /// multiple matches in the right DataFrame, those rows are adjacent in the output.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -414,7 +654,122 @@ pub trait DataFrameJoinOps: IntoDf {
         self.join(other, left_on, right_on, JoinType::Left, None)
     }
 
+    /// Perform a left join on two DataFrames and fill the right columns of the left rows that
+    /// have no match with `fill_value`, instead of with nulls.
+    ///
+    /// Nulls that are present in the right DataFrame itself are kept. `fill_value` is cast to
+    /// the dtype of every right column; if it can't be represented in that dtype, e.g. `"a"` or
+    /// `1.5` for an integer column, an error is returned. A float is rounded to the precision of
+    /// a narrower float column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     left.left_join_fill(right, ["days"], ["days"], AnyValue::Int32(0))
+    /// }
+    /// ```
+    fn left_join_fill<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        fill_value: AnyValue,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let left_df = self.to_df();
+        let selected_left = left_df.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        let args = JoinArgs::new(JoinType::Left);
+        let (idx_left, idx_right) =
+            join_indices_from_series(&selected_left, &selected_right, &args)?;
+        // the left rows without a match get a null right index
+        let unmatched = idx_right.is_null();
+
+        let right_values = remove_selected(other, &selected_right);
+        let (df_left, df_right) =
+            POOL.join(|| left_df.take(&idx_left), || right_values.take(&idx_right));
+        let mut df_right = df_right?;
+        let fill_value = Series::from_any_values("", &[fill_value], false)?;
+        for idx in 0..df_right.width() {
+            df_right.try_apply_at_idx(idx, |s| {
+                let fill = fill_value.strict_cast(s.dtype())?;
+                // a numeric cast may truncate without producing a null, e.g. 1.5 to 1
+                // narrowing a float only rounds it, e.g. 0.1 to a Float32 column
+                let check_lossless = fill_value.dtype().is_numeric()
+                    && s.dtype().is_numeric()
+                    && !(fill_value.dtype().is_float() && s.dtype().is_float());
+                if check_lossless {
+                    let lossless = fill.cast(fill_value.dtype())?.series_equal(&fill_value);
+                    polars_ensure!(
+                        lossless,
+                        ComputeError: "cannot fill column '{}' of dtype {} with {}: the value \
+                        can't be represented in that dtype",
+                        s.name(), s.dtype(), fill_value.get(0)?
+                    );
+                }
+                fill_masked(s, &unmatched, &fill)
+            })?;
+        }
+        _finish_join(df_left?, df_right, None)
+    }
+
+    /// Perform a left join on two DataFrames and count how many rows found a match.
+    ///
+    /// The [`JoinStats`] are computed from the join indices before the output is materialized.
+    /// A low [`JoinStats::left_match_rate`] can reveal keys that silently don't match, e.g.
+    /// because of different casing or padding.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     let (out, stats) = left.left_join_with_stats(right, ["key"], ["key"])?;
+    ///     if stats.left_matched < stats.left_rows {
+    ///         eprintln!("{} left rows have no match", stats.left_rows - stats.left_matched);
+    ///     }
+    ///     Ok(out)
+    /// }
+    /// ```
+    fn left_join_with_stats<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+    ) -> PolarsResult<(DataFrame, JoinStats)>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let left_df = self.to_df();
+        let selected_left = left_df.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        let args = JoinArgs::new(JoinType::Left);
+        let (idx_left, idx_right) =
+            join_indices_from_series(&selected_left, &selected_right, &args)?;
+        let stats =
+            JoinStats::from_join_indices(&idx_left, &idx_right, left_df.height(), other.height());
+
+        let right_values = remove_selected(other, &selected_right);
+        let (df_left, df_right) =
+            POOL.join(|| left_df.take(&idx_left), || right_values.take(&idx_right));
+        let out = _finish_join(df_left?, df_right?, None)?;
+        Ok((out, stats))
+    }
+
     /// Perform an outer join on two DataFrames
+    ///
+    /// The output columns are ordered as follows: the coalesced key column(s) first, then the
+    /// remaining left columns and finally the remaining right columns, suffixed on name
+    /// collisions.
+    ///
     /// # Example
     ///
     /// ```
@@ -435,6 +790,158 @@ pub trait DataFrameJoinOps: IntoDf {
     {
         self.join(other, left_on, right_on, JoinType::Outer, None)
     }
+
+    /// Join two DataFrames on key columns that have the same names in both frames.
+    ///
+    /// This is the same as [`DataFrameJoinOps::join`] with `on` as both the left and the right
+    /// keys. The output has a single column per key, like the other joins.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     left.join_on(right, ["year", "month"], JoinType::Left)
+    /// }
+    /// ```
+    fn join_on<I, S>(&self, other: &DataFrame, on: I, how: JoinType) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let on = on
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect::<Vec<_>>();
+        self.join(other, &on, &on, how, None)
+    }
+
+    /// Perform an inner join on a key column that has the same name in both frames.
+    fn inner_join_on(&self, other: &DataFrame, on: &str) -> PolarsResult<DataFrame> {
+        self.join_on(other, [on], JoinType::Inner)
+    }
+
+    /// Perform a left join on a key column that has the same name in both frames.
+    fn left_join_on(&self, other: &DataFrame, on: &str) -> PolarsResult<DataFrame> {
+        self.join_on(other, [on], JoinType::Left)
+    }
+
+    /// Perform an outer join on a key column that has the same name in both frames.
+    fn outer_join_on(&self, other: &DataFrame, on: &str) -> PolarsResult<DataFrame> {
+        self.join_on(other, [on], JoinType::Outer)
+    }
+
+    /// Join this DataFrame on `left_on` with the mapping given by the parallel Series `keys`
+    /// and `values`.
+    ///
+    /// This is the same as joining with a DataFrame that consists of the columns `keys` and
+    /// `values`, joined on `keys`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn add_names(df: &DataFrame, ids: &Series, names: &Series) -> PolarsResult<DataFrame> {
+    ///     df.join_series(ids, names, "id", JoinType::Left)
+    /// }
+    /// ```
+    fn join_series(
+        &self,
+        keys: &Series,
+        values: &Series,
+        left_on: &str,
+        how: JoinType,
+    ) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            keys.len() == values.len(),
+            ShapeMismatch: "join keys and values must have the same length, got {} and {}",
+            keys.len(), values.len()
+        );
+        let other = DataFrame::new(vec![keys.clone(), values.clone()])?;
+        self.join(&other, [left_on], [keys.name()], how, None)
+    }
+
+    /// Perform an inner join that partitions both DataFrames by the hash of their keys and
+    /// joins them partition by partition.
+    ///
+    /// Only the hash table of a single partition is alive at a time. The row indices of all
+    /// partitions and the join tuples are still materialized at once, so only the memory of the
+    /// hash tables is bounded. The result contains the same rows as
+    /// [`inner_join`](DataFrameJoinOps::inner_join), but their order may differ. `partitions`
+    /// is clamped to the number of rows of the larger frame.
+    fn inner_join_partitioned<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        partitions: usize,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        polars_ensure!(
+            partitions > 0,
+            ComputeError: "number of partitions must be greater than zero"
+        );
+        let left_df = self.to_df();
+        let selected_left = left_df.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        check_join_keys(&selected_left, &selected_right)?;
+        // more partitions than rows would only allocate empty partitions
+        let partitions = partitions.min(std::cmp::max(left_df.height(), other.height()).max(1));
+        // equal strings must get equal codes, or they end up in different partitions
+        #[cfg(feature = "dtype-categorical")]
+        let (selected_left, selected_right) =
+            unify_categorical_series(selected_left, selected_right)?;
+
+        // both sides must be hashed with the same state
+        let build_hasher = ahash::RandomState::new();
+        let parts_left = partition_by_key_hash(&selected_left, partitions, &build_hasher)?;
+        let parts_right = partition_by_key_hash(&selected_right, partitions, &build_hasher)?;
+
+        let args = JoinArgs::new(JoinType::Inner);
+        let mut idx_left = Vec::new();
+        let mut idx_right = Vec::new();
+        for (part_left, part_right) in parts_left.into_iter().zip(parts_right) {
+            if part_left.is_empty() || part_right.is_empty() {
+                continue;
+            }
+            let part_left = IdxCa::from_vec("", part_left);
+            let part_right = IdxCa::from_vec("", part_right);
+            let keys_left = selected_left
+                .iter()
+                .map(|s| s.take(&part_left))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let keys_right = selected_right
+                .iter()
+                .map(|s| s.take(&part_right))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let (local_left, local_right) =
+                join_indices_from_series(&keys_left, &keys_right, &args)?;
+            // map the indices into the partition back to the rows of the frames
+            let part_left = part_left.cont_slice().unwrap();
+            let part_right = part_right.cont_slice().unwrap();
+            idx_left.extend(
+                local_left
+                    .into_no_null_iter()
+                    .map(|i| part_left[i as usize]),
+            );
+            idx_right.extend(
+                local_right
+                    .into_no_null_iter()
+                    .map(|i| part_right[i as usize]),
+            );
+        }
+
+        let idx_left = IdxCa::from_vec("", idx_left);
+        let idx_right = IdxCa::from_vec("", idx_right);
+        let other = remove_selected(other, &selected_right);
+        let (df_left, df_right) = POOL.join(|| left_df.take(&idx_left), || other.take(&idx_right));
+        _finish_join(df_left?, df_right?, None)
+    }
 }
 
 trait DataFrameJoinOpsPrivate: IntoDf {
@@ -443,6 +950,7 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         self.to_df().height()
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn _inner_join_from_series(
         &self,
         other: &DataFrame,
@@ -450,13 +958,37 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         s_right: &Series,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
+        maintain_order: bool,
         verbose: bool,
+        explosion_factor: Option<f64>,
+        expected_size: Option<usize>,
     ) -> PolarsResult<DataFrame> {
         let left_df = self.to_df();
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
-        let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose);
+        let left_names = _join_left_names(left_df)?;
+        // no need to hash if there is nothing to match
+        if left_df.height() == 0 || other.height() == 0 {
+            return _finish_join_with_left_names(
+                left_df.clear(),
+                other.drop(s_right.name())?.clear(),
+                suffix.as_deref(),
+                &left_names,
+            );
+        }
+        let ((mut join_tuples_left, mut join_tuples_right), mut sorted) =
+            _sort_or_hash_inner(s_left, s_right, verbose, expected_size);
+        _check_join_explosion(
+            explosion_factor,
+            join_tuples_left.len(),
+            left_df.height(),
+            other.height(),
+        )?;
+
+        if maintain_order {
+            _sort_inner_join_tuples(&mut join_tuples_left, &mut join_tuples_right);
+            sorted = true;
+        }
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
@@ -476,7 +1008,374 @@ trait DataFrameJoinOpsPrivate: IntoDf {
                     ._take_unchecked_slice(join_tuples_right, true)
             },
         );
-        _finish_join(df_left, df_right, suffix.as_deref())
+        _finish_join_with_left_names(df_left, df_right, suffix.as_deref(), &left_names)
+    }
+}
+
+/// The non-null mask of the left keys if nulls don't match, as a `Vec` for random access.
+fn join_keys_not_null_vec(selected_left: &[Series], args: &JoinArgs) -> Option<Vec<bool>> {
+    if args.join_nulls {
+        return None;
+    }
+    _join_keys_not_null(selected_left).map(|mask| mask.into_no_null_iter().collect())
+}
+
+/// Re-encode the categorical keys of different sources, and their columns in the frames, with
+/// a shared dictionary, so that they can be joined on their codes.
+#[cfg(feature = "dtype-categorical")]
+fn unify_categorical_keys(
+    left_df: &DataFrame,
+    right_df: &DataFrame,
+    mut selected_left: Vec<Series>,
+    mut selected_right: Vec<Series>,
+) -> PolarsResult<(DataFrame, DataFrame, Vec<Series>, Vec<Series>)> {
+    let mut left_df = left_df.clone();
+    let mut right_df = right_df.clone();
+    for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
+        if let Some((l_unified, r_unified)) = unify_categorical_pair(l, r)? {
+            *l = l_unified;
+            *r = r_unified;
+            for (df, s) in [(&mut left_df, &*l), (&mut right_df, &*r)] {
+                if df.find_idx_by_name(s.name()).is_some() {
+                    df.with_column(s.clone())?;
+                }
+            }
+        }
+    }
+    Ok((left_df, right_df, selected_left, selected_right))
+}
+
+/// Like [`unify_categorical_keys`], for keys that are only used to compute the join indices.
+#[cfg(feature = "dtype-categorical")]
+fn unify_categorical_series(
+    mut selected_left: Vec<Series>,
+    mut selected_right: Vec<Series>,
+) -> PolarsResult<(Vec<Series>, Vec<Series>)> {
+    for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
+        if let Some((l_unified, r_unified)) = unify_categorical_pair(l, r)? {
+            *l = l_unified;
+            *r = r_unified;
+        }
+    }
+    Ok((selected_left, selected_right))
+}
+
+/// Re-encode two categorical keys from different sources with a shared dictionary. Returns
+/// `None` if the keys can be joined on their codes as they are.
+#[cfg(feature = "dtype-categorical")]
+fn unify_categorical_pair(l: &Series, r: &Series) -> PolarsResult<Option<(Series, Series)>> {
+    if _check_categorical_src(l.dtype(), r.dtype()).is_ok() {
+        return Ok(None);
+    }
+    let (l_ca, r_ca) = l.categorical()?.unify_rev_maps(r.categorical()?);
+    Ok(Some((l_ca.into_series(), r_ca.into_series())))
+}
+
+fn remove_selected(df: &DataFrame, selected: &[Series]) -> DataFrame {
+    // a single pass over the columns, the series are only reference counted
+    let names = selected.iter().map(|s| s.name()).collect::<Vec<_>>();
+    df.drop_many(&names)
+}
+
+/// Split the row indices of `keys` into `partitions` groups by the high bits of the key hashes.
+/// Equal keys always end up in the same group.
+fn partition_by_key_hash(
+    keys: &[Series],
+    partitions: usize,
+    build_hasher: &ahash::RandomState,
+) -> PolarsResult<Vec<Vec<IdxSize>>> {
+    let keys = _to_physical_and_bit_repr(keys);
+    let mut hashes = Vec::with_capacity(keys[0].len());
+    keys[0].0.vec_hash(build_hasher.clone(), &mut hashes)?;
+    for s in &keys[1..] {
+        s.0.vec_hash_combine(build_hasher.clone(), &mut hashes)?;
+    }
+
+    let mut parts = vec![Vec::new(); partitions];
+    for (idx, h) in hashes.into_iter().enumerate() {
+        // the high bits, as the low bits also select the partition of the hash tables
+        let part = ((h as u128 * partitions as u128) >> 64) as usize;
+        parts[part].push(idx as IdxSize);
+    }
+    Ok(parts)
+}
+
+/// Replace the nulls of the join `keys` of `df` with `value`, in the keys and in the frame.
+fn fill_null_keys(
+    df: &DataFrame,
+    keys: &[Series],
+    value: &AnyValue<'static>,
+) -> PolarsResult<(DataFrame, Vec<Series>)> {
+    let mut df = df.clone();
+    let keys = keys
+        .iter()
+        .map(|s| {
+            if s.null_count() == 0 {
+                return Ok(s.clone());
+            }
+            let fill = Series::full(s.name(), value.clone(), 1)?.strict_cast(s.dtype())?;
+            let filled = fill_masked(s, &s.is_null(), &fill)?;
+            if df.find_idx_by_name(s.name()).is_some() {
+                df.replace(s.name(), filled.clone())?;
+            }
+            Ok(filled)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok((df, keys))
+}
+
+/// Replace the values of `s` where `mask` is `true` with the single value of `fill`, which must
+/// have the dtype of `s`.
+fn fill_masked(s: &Series, mask: &BooleanChunked, fill: &Series) -> PolarsResult<Series> {
+    // `fill` is appended, so its value is at index `len`
+    let len = s.len() as IdxSize;
+    let idx = mask
+        .into_iter()
+        .zip(0..len)
+        .map(|(masked, idx)| if masked == Some(true) { len } else { idx })
+        .collect::<Vec<_>>();
+    let mut values = s.clone();
+    values.append(fill)?;
+    values.take(&IdxCa::from_vec("", idx))
+}
+
+/// Join on the keys passed through the `key_map` of [`JoinArgs`], while the output keeps the
+/// original key values.
+fn join_on_mapped_keys(
+    left_df: &DataFrame,
+    other: &DataFrame,
+    selected_left: &[Series],
+    selected_right: &[Series],
+    key_map: &JoinKeyMap,
+    args: &JoinArgs,
+) -> PolarsResult<DataFrame> {
+    let map_keys = |keys: &[Series]| {
+        keys.iter()
+            .map(|s| {
+                let out = key_map.call(s);
+                polars_ensure!(
+                    out.len() == s.len(),
+                    ShapeMismatch: "the key map changed the length of join key '{}' from {} to {}",
+                    s.name(), s.len(), out.len()
+                );
+                Ok(out)
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    };
+    let mapped_left = map_keys(selected_left)?;
+    let mapped_right = map_keys(selected_right)?;
+    for (l, r) in mapped_left.iter().zip(&mapped_right) {
+        polars_ensure!(
+            l.dtype() == r.dtype(),
+            SchemaMismatch: "the key map returned dtype {} for the left join key '{}', \
+            but dtype {} for the right join key '{}'",
+            l.dtype(), l.name(), r.dtype(), r.name()
+        );
+    }
+    let (idx_left, idx_right) = join_indices_from_series(&mapped_left, &mapped_right, args)?;
+    if !args.coalesce_key {
+        let (df_left, df_right) = POOL.join(|| left_df.take(&idx_left), || other.take(&idx_right));
+        return _finish_join(df_left?, df_right?, args.suffix.as_deref());
+    }
+
+    let right_keys = selected_right.iter().map(|s| s.name()).collect::<Vec<_>>();
+    let right_values = other.drop_many(&right_keys);
+    match args.how {
+        JoinType::Outer => {
+            // the key of a row without a left match is taken from the right frame
+            let opt_join_tuples = (&idx_left).into_iter().zip(&idx_right).collect::<Vec<_>>();
+            let left_keys = selected_left.iter().map(|s| s.name()).collect::<Vec<_>>();
+            let left_values = left_df.drop_many(&left_keys);
+            let mut keys = Vec::with_capacity(left_df.width());
+            for (s_left, s_right) in selected_left.iter().zip(selected_right) {
+                let s_right = s_right.cast(s_left.dtype())?;
+                let mut s = s_left.zip_outer_join_column(&s_right, &opt_join_tuples);
+                s.rename(s_left.name());
+                keys.push(s);
+            }
+            let (df_left, df_right) = POOL.join(
+                || left_values.take(&idx_left),
+                || right_values.take(&idx_right),
+            );
+            keys.extend_from_slice(df_left?.get_columns());
+            _finish_join(
+                DataFrame::new_no_checks(keys),
+                df_right?,
+                args.suffix.as_deref(),
+            )
+        }
+        _ => {
+            let (df_left, df_right) =
+                POOL.join(|| left_df.take(&idx_left), || right_values.take(&idx_right));
+            _finish_join(df_left?, df_right?, args.suffix.as_deref())
+        }
+    }
+}
+
+/// Check that the left and right join keys pair up and have equal dtypes.
+fn check_join_keys(selected_left: &[Series], selected_right: &[Series]) -> PolarsResult<()> {
+    polars_ensure!(
+        selected_left.len() == selected_right.len(),
+        ShapeMismatch: "the number of columns given as join key should be equal, got {} left and {} right",
+        selected_left.len(), selected_right.len()
+    );
+    polars_ensure!(
+        !selected_left.is_empty(),
+        ShapeMismatch: "expected at least one join key"
+    );
+    for (l, r) in selected_left.iter().zip(selected_right) {
+        polars_ensure!(
+            l.dtype() == r.dtype(),
+            SchemaMismatch: "datatypes of join keys don't match: left key '{}' has dtype {}, \
+            right key '{}' has dtype {}",
+            l.name(), l.dtype(), r.name(), r.dtype()
+        );
+    }
+    Ok(())
+}
+
+fn join_indices_from_series(
+    selected_left: &[Series],
+    selected_right: &[Series],
+    args: &JoinArgs,
+) -> PolarsResult<(IdxCa, IdxCa)> {
+    check_join_keys(selected_left, selected_right)?;
+    #[cfg(feature = "dtype-categorical")]
+    let (selected_left, selected_right) =
+        &unify_categorical_series(selected_left.to_vec(), selected_right.to_vec())?;
+    args.validation.validate(selected_left, selected_right)?;
+
+    // the row hashing of the multiple keys join also works for a single key
+    let mut left = DataFrame::new_no_checks(_to_physical_and_bit_repr(selected_left));
+    let mut right = DataFrame::new_no_checks(_to_physical_and_bit_repr(selected_right));
+    let (n_left, n_right) = (left.height(), right.height());
+    let (idx_left, idx_right) = match args.how {
+        JoinType::Inner => {
+            let (mut idx_left, mut idx_right) = if left.height() == 0 || right.height() == 0 {
+                (vec![], vec![])
+            } else {
+                let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
+                _inner_join_multiple_keys(&mut left, &mut right, swap, args.hash_seed)
+            };
+            if let Some(valid) = join_keys_not_null_vec(selected_left, args) {
+                // a left null key only matches right null keys
+                (idx_left, idx_right) = idx_left
+                    .into_iter()
+                    .zip(idx_right)
+                    .filter(|(left, _)| valid[*left as usize])
+                    .unzip();
+            }
+            if args.maintain_order {
+                _sort_inner_join_tuples(&mut idx_left, &mut idx_right);
+            }
+            (
+                IdxCa::from_vec("left", idx_left),
+                IdxCa::from_vec("right", idx_right),
+            )
+        }
+        JoinType::Left => {
+            let (idx_left, idx_right) = if left.height() == 0 || right.height() == 0 {
+                _left_join_ids_no_matches(left.height())
+            } else {
+                _left_join_multiple_keys(&mut left, &mut right, None, None, args.hash_seed)
+            };
+            // without a chunk mapping the ids are never chunked
+            #[cfg(feature = "chunked_ids")]
+            let (mut idx_left, mut idx_right) =
+                (idx_left.left().unwrap(), idx_right.left().unwrap());
+            #[cfg(not(feature = "chunked_ids"))]
+            let (mut idx_left, mut idx_right) = (idx_left, idx_right);
+            if args.reverse_matches {
+                _reverse_left_join_matches(&idx_left, &mut idx_right);
+            }
+            if let Some(valid) = join_keys_not_null_vec(selected_left, args) {
+                // the matches of a left row are consecutive, so a left row with a null key
+                // gets a single unmatched tuple after the dedup
+                let mut tuples = idx_left
+                    .into_iter()
+                    .zip(idx_right)
+                    .map(|(left, right)| (left, right.filter(|_| valid[left as usize])))
+                    .collect::<Vec<_>>();
+                tuples.dedup();
+                (idx_left, idx_right) = tuples.into_iter().unzip();
+            }
+            (
+                IdxCa::from_vec("left", idx_left),
+                IdxCa::from_slice_options("right", &idx_right),
+            )
+        }
+        JoinType::Outer => {
+            let mut opt_join_tuples = if left.height() == 0 || right.height() == 0 {
+                _outer_join_tuples_no_matches(left.height(), right.height())
+            } else {
+                let (mut left, mut right, swap) = det_hash_prone_order!(left, right);
+                _outer_join_multiple_keys(&mut left, &mut right, swap, args.hash_seed)
+            };
+            if !args.join_nulls {
+                opt_join_tuples = _outer_join_tuples_unmatch_nulls(
+                    opt_join_tuples,
+                    selected_left,
+                    selected_right,
+                );
+            }
+            (
+                IdxCa::from_iter_options("left", opt_join_tuples.iter().map(|t| t.0)),
+                IdxCa::from_iter_options("right", opt_join_tuples.iter().map(|t| t.1)),
+            )
+        }
+        how => polars_bail!(
+            InvalidOperation: "join indices are not supported for {} joins", how
+        ),
+    };
+    _check_join_explosion(args.explosion_factor, idx_left.len(), n_left, n_right)?;
+
+    Ok(match args.slice {
+        Some((offset, len)) => (idx_left.slice(offset, len), idx_right.slice(offset, len)),
+        None => (idx_left, idx_right),
+    })
+}
+
+/// Statistics of a join, see [`DataFrameJoinOps::left_join_with_stats`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct JoinStats {
+    /// The number of rows of the left frame.
+    pub left_rows: usize,
+    /// The number of left rows that match at least one right row.
+    pub left_matched: usize,
+    /// The number of right rows that match at least one left row.
+    pub right_matched: usize,
+    /// The number of rows of the output.
+    pub output_rows: usize,
+}
+
+impl JoinStats {
+    fn from_join_indices(
+        idx_left: &IdxCa,
+        idx_right: &IdxCa,
+        n_left: usize,
+        n_right: usize,
+    ) -> Self {
+        let mut left_matched = vec![false; n_left];
+        let mut right_matched = vec![false; n_right];
+        for (left, right) in idx_left.into_iter().zip(idx_right) {
+            if let (Some(left), Some(right)) = (left, right) {
+                left_matched[left as usize] = true;
+                right_matched[right as usize] = true;
+            }
+        }
+        let count = |matched: Vec<bool>| matched.into_iter().filter(|m| *m).count();
+        JoinStats {
+            left_rows: n_left,
+            left_matched: count(left_matched),
+            right_matched: count(right_matched),
+            output_rows: idx_left.len(),
+        }
+    }
+
+    /// The fraction of left rows that match at least one right row, `None` if the left frame
+    /// is empty.
+    pub fn left_match_rate(&self) -> Option<f64> {
+        (self.left_rows > 0).then(|| self.left_matched as f64 / self.left_rows as f64)
     }
 }
 
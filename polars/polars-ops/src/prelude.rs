@@ -4,5 +4,5 @@ pub(crate) use {crate::series::*, polars_core::export::rayon::prelude::*};
 pub use crate::chunked_array::*;
 #[cfg(feature = "merge_sorted")]
 pub use crate::frame::_merge_sorted_dfs;
-pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
+pub use crate::frame::{DataFrameJoinOps, DataFrameOps, JoinStats};
 pub use crate::series::*;
@@ -85,7 +85,7 @@ fn arg_max_bool(ca: &BooleanChunked) -> Option<usize> {
 }
 
 fn arg_min_bool(ca: &BooleanChunked) -> Option<usize> {
-    if ca.is_empty() || ca.null_count() == ca.len() || ca.all() {
+    if ca.is_empty() || ca.null_count() == ca.len() || ca.all_kleene() == Some(true) {
         Some(0)
     } else if ca.null_count() == 0 && ca.chunks().len() == 1 {
         let arr = ca.downcast_iter().next().unwrap();
@@ -3,7 +3,13 @@ use polars_core::export::ahash;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 
+#[cfg(feature = "interpolate")]
+use crate::chunked_array::{interpolate, InterpolationMethod};
 use crate::series::ops::SeriesSealed;
+#[cfg(feature = "is_unique")]
+use crate::series::ops::{is_duplicated, is_unique};
+#[cfg(feature = "search_sorted")]
+use crate::series::ops::{search_sorted, SearchSortedSide};
 
 pub trait SeriesMethods: SeriesSealed {
     /// Create a [`DataFrame`] with the unique `values` of this [`Series`] and a column `"counts"`
@@ -57,6 +63,100 @@ pub trait SeriesMethods: SeriesSealed {
         let out = s.sort_with(options);
         out.eq(s)
     }
+
+    /// Get a mask that is `true` where a value occurs exactly once in this [`Series`].
+    ///
+    /// Nulls are counted as a value.
+    #[cfg(feature = "is_unique")]
+    fn is_unique(&self) -> PolarsResult<BooleanChunked> {
+        is_unique(self.as_series())
+    }
+
+    /// Get a mask that is `true` where a value occurs more than once in this [`Series`].
+    ///
+    /// Nulls are counted as a value.
+    #[cfg(feature = "is_unique")]
+    fn is_duplicated(&self) -> PolarsResult<BooleanChunked> {
+        is_duplicated(self.as_series())
+    }
+
+    /// Find the index at which `value` should be inserted to keep this [`Series`] sorted.
+    ///
+    /// The [`Series`] must be flagged as sorted, e.g. by calling [`Series::sort`] on it.
+    /// If `value` is already present, the index of the first occurrence is returned.
+    #[cfg(feature = "search_sorted")]
+    fn search_sorted(&self, value: AnyValue) -> PolarsResult<usize> {
+        let s = self.as_series();
+        let descending = match s.is_sorted_flag() {
+            IsSorted::Ascending => false,
+            IsSorted::Descending => true,
+            IsSorted::Not => polars_bail!(
+                InvalidOperation: "cannot search series '{}': it is not flagged as sorted", s.name()
+            ),
+        };
+        let search_value = Series::from_any_values_and_dtype(s.name(), &[value], s.dtype(), false)?;
+        let idx = search_sorted(s, &search_value, SearchSortedSide::Left, descending)?;
+        Ok(idx.get(0).unwrap() as usize)
+    }
+
+    /// Fill the interior nulls of this [`Series`] from the surrounding non-null values.
+    ///
+    /// Leading and trailing nulls stay null.
+    #[cfg(feature = "interpolate")]
+    fn interpolate(&self, method: InterpolationMethod) -> Series {
+        interpolate(self.as_series(), method)
+    }
+
+    /// Count the values of this [`Series`] in `bins` equal-width buckets spanning its minimum
+    /// and maximum.
+    ///
+    /// Returns a [`DataFrame`] with the columns `"lower_bound"`, `"upper_bound"` and `"count"`.
+    /// A bucket includes its lower bound; the last bucket also includes the maximum. Nulls, `NaN`
+    /// and infinite values are not counted and don't affect the bounds. If all values are equal,
+    /// a single bucket is returned.
+    fn hist(&self, bins: usize) -> PolarsResult<DataFrame> {
+        let s = self.as_series();
+        polars_ensure!(
+            s.dtype().is_numeric(),
+            InvalidOperation: "cannot compute histogram of series of dtype {}", s.dtype()
+        );
+        polars_ensure!(bins > 0, ComputeError: "number of bins must be greater than zero");
+        let s = s.cast(&DataType::Float64)?;
+        let ca = s.f64().unwrap();
+        let s = ca.filter(&ca.is_finite())?.into_series();
+        let ca = s.f64().unwrap();
+
+        let min_max = s
+            .min_max()
+            .map(|(min, max)| (min.extract::<f64>().unwrap(), max.extract::<f64>().unwrap()));
+        let (lower, upper, counts) = match min_max {
+            Some((min, max)) if min == max => {
+                let count = (ca.len() - ca.null_count()) as IdxSize;
+                (vec![min], vec![max], vec![count])
+            }
+            Some((min, max)) => {
+                let width = (max - min) / bins as f64;
+                let mut counts = vec![0 as IdxSize; bins];
+                ca.into_iter().flatten().for_each(|v| {
+                    // the maximum falls exactly on the last upper bound
+                    let idx = (((v - min) / width) as usize).min(bins - 1);
+                    counts[idx] += 1;
+                });
+                let lower = (0..bins).map(|i| min + i as f64 * width).collect();
+                let upper = (1..=bins).map(|i| min + i as f64 * width).collect();
+                (lower, upper, counts)
+            }
+            // no valid values
+            _ => (vec![], vec![], vec![]),
+        };
+
+        let cols = vec![
+            Float64Chunked::from_vec("lower_bound", lower).into_series(),
+            Float64Chunked::from_vec("upper_bound", upper).into_series(),
+            IdxCa::from_vec("count", counts).into_series(),
+        ];
+        Ok(DataFrame::new_no_checks(cols))
+    }
 }
 
 impl SeriesMethods for Series {}
@@ -4,11 +4,15 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use polars_core::prelude::*;
+#[cfg(feature = "timezones")]
+use polars_core::utils::arrow::temporal_conversions::parse_offset;
 use polars_core::utils::Wrap;
 pub use SeriesOpsTime;
 
 pub use self::_trait::*;
 use crate::chunkedarray::*;
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+use crate::prelude::{Duration, PolarsTruncate, NO_TIMEZONE};
 
 type SeriesOpsRef = Arc<dyn SeriesOpsTime>;
 
@@ -331,6 +335,40 @@ pub trait TemporalMethods: AsSeries {
                 .map(|s| s.datetime().unwrap().deref().clone())
         }
     }
+
+    #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+    /// Floor every date or timestamp to a multiple of `every`, e.g. to the start of its hour or
+    /// day. Nulls stay null. A datetime is truncated in its own time zone.
+    fn truncate(&self, every: Duration) -> PolarsResult<Series> {
+        let s = self.as_series();
+        let offset = Duration::new(0);
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => Ok(s
+                .date()?
+                .truncate(every, offset, NO_TIMEZONE)?
+                .into_series()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, tz) => {
+                let ca = s.datetime()?;
+                let out = match tz {
+                    #[cfg(feature = "timezones")]
+                    Some(tz) => match tz.parse::<chrono_tz::Tz>() {
+                        Ok(tz) => ca.truncate(every, offset, Some(&tz))?,
+                        Err(_) => {
+                            let tz = parse_offset(tz).map_err(
+                                |_| polars_err!(ComputeError: "unable to parse time zone: '{}'", tz),
+                            )?;
+                            ca.truncate(every, offset, Some(&tz))?
+                        }
+                    },
+                    _ => ca.truncate(every, offset, NO_TIMEZONE)?,
+                };
+                Ok(out.into_series())
+            }
+            dt => polars_bail!(opq = truncate, dt),
+        }
+    }
 }
 
 impl<T: ?Sized + AsSeries> TemporalMethods for T {}
@@ -51,6 +51,41 @@ fn test_datelike_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-date")]
+#[cfg_attr(miri, ignore)]
+fn test_date_join() -> PolarsResult<()> {
+    let left = DataFrame::new(vec![
+        Series::new("date", &[0, 1, 2]).cast(&DataType::Date)?,
+        Series::new("temp", &[22.1, 19.9, 7.]),
+    ])?;
+    let right = DataFrame::new(vec![
+        Series::new("date", &[1, 2, 3]).cast(&DataType::Date)?,
+        Series::new("rain", &[0.1, 0.2, 0.3]),
+    ])?;
+
+    let out = left.inner_join(&right, ["date"], ["date"])?;
+    assert_eq!(out.column("date")?.dtype(), &DataType::Date);
+    assert_eq!(out.height(), 2);
+
+    let out = left.left_join(&right, ["date"], ["date"])?;
+    assert_eq!(out.column("date")?.dtype(), &DataType::Date);
+    assert_eq!(out.column("rain")?.null_count(), 1);
+
+    // the keys of the right frame are coalesced into the date column
+    let out = left.outer_join(&right, ["date"], ["date"])?;
+    assert_eq!(out.column("date")?.dtype(), &DataType::Date);
+    let mut dates = out
+        .column("date")?
+        .to_physical_repr()
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    dates.sort_unstable();
+    assert_eq!(dates, &[0, 1, 2, 3]);
+    Ok(())
+}
+
 #[test]
 #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
 fn test_datelike_methods() -> PolarsResult<()> {
@@ -159,3 +194,108 @@ fn test_duration() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn test_cast_time_units() -> PolarsResult<()> {
+    const NS_IN_DAY: i64 = 86_400_000_000_000;
+    fn physical(s: &Series) -> Vec<Option<i64>> {
+        Vec::from(s.to_physical_repr().i64().unwrap())
+    }
+    let units = [
+        (TimeUnit::Milliseconds, 1_000_000i64),
+        (TimeUnit::Microseconds, 1_000),
+        (TimeUnit::Nanoseconds, 1),
+    ];
+
+    let days = Series::new("date", &[Some(1), None, Some(-2)]).cast(&DataType::Date)?;
+    for (tu, ns_per_unit) in units {
+        let out = days.cast(&DataType::Datetime(tu, None))?;
+        let per_day = NS_IN_DAY / ns_per_unit;
+        assert_eq!(physical(&out), &[Some(per_day), None, Some(-2 * per_day)]);
+    }
+
+    let values = Int64Chunked::new("v", &[Some(3_000_000), None, Some(-6_000_000)]);
+    for (from, from_ns) in units {
+        let datetime = values.clone().into_datetime(from, None).into_series();
+        let duration = values.clone().into_duration(from).into_series();
+        for (to, to_ns) in units {
+            let expected = values
+                .into_iter()
+                .map(|v| v.map(|v| v * from_ns / to_ns))
+                .collect::<Vec<_>>();
+            let out = datetime.cast(&DataType::Datetime(to, None))?;
+            assert_eq!(out.dtype(), &DataType::Datetime(to, None));
+            assert_eq!(physical(&out), expected);
+            let out = duration.cast(&DataType::Duration(to))?;
+            assert_eq!(out.dtype(), &DataType::Duration(to));
+            assert_eq!(physical(&out), expected);
+        }
+    }
+
+    // upscaling values that don't fit into an i64 is an error
+    let large = Int64Chunked::new("v", &[Some(i64::MAX / 10), None]);
+    assert!(large
+        .clone()
+        .into_datetime(TimeUnit::Milliseconds, None)
+        .into_series()
+        .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+        .is_err());
+    assert!(large
+        .into_duration(TimeUnit::Microseconds)
+        .into_series()
+        .cast(&DataType::Duration(TimeUnit::Nanoseconds))
+        .is_err());
+    let far_away = Series::new("date", &[i32::MAX]).cast(&DataType::Date)?;
+    assert!(far_away
+        .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))
+        .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "temporal",
+    feature = "dtype-datetime",
+    feature = "dtype-date"
+))]
+fn test_truncate_datetime_to_day() -> PolarsResult<()> {
+    const MS_IN_DAY: i64 = 86_400_000;
+    let s = Series::new(
+        "ts",
+        &[
+            Some(0),
+            Some(MS_IN_DAY - 1),
+            None,
+            Some(3 * MS_IN_DAY + 12_345),
+            Some(-1),
+        ],
+    )
+    .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+
+    let out = s.truncate(Duration::parse("1d"))?;
+    assert_eq!(out.name(), "ts");
+    assert_eq!(out.dtype(), s.dtype());
+    let out = out.cast(&DataType::Int64)?;
+    assert_eq!(
+        Vec::from(out.i64()?),
+        &[
+            Some(0),
+            Some(0),
+            None,
+            Some(3 * MS_IN_DAY),
+            Some(-MS_IN_DAY)
+        ]
+    );
+
+    let dates = Series::new("d", &[Some(0i32), Some(10), None]).cast(&DataType::Date)?;
+    let out = dates
+        .truncate(Duration::parse("1w"))?
+        .cast(&DataType::Int32)?;
+    assert_eq!(Vec::from(out.i32()?), &[Some(-3), Some(4), None]);
+
+    assert!(Series::new("a", &[1, 2])
+        .truncate(Duration::parse("1d"))
+        .is_err());
+    Ok(())
+}
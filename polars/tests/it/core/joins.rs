@@ -90,6 +90,12 @@ fn test_left_join() {
             3.
         );
         assert_eq!(joined.column("rain").unwrap().null_count(), 3);
+        let null_counts = joined.null_count();
+        assert_eq!(null_counts.shape(), (1, 3));
+        assert_eq!(
+            null_counts.column("rain").unwrap().idx().unwrap().get(0),
+            Some(3)
+        );
 
         // test join on utf8
         let s0 = Series::new("days", &["mo", "tue", "wed", "thu", "fri"]);
@@ -285,8 +291,9 @@ fn test_join_categorical() {
         assert_eq!(out.dtype(), &DataType::Categorical(None));
     }
 
-    // Test error when joining on different string cache
+    // joining on different string caches unifies the dictionaries
     let (mut df_a, mut df_b) = get_dfs();
+    let (df_a_utf8, df_b_utf8) = get_dfs();
     df_a.try_apply("b", |s| s.cast(&DataType::Categorical(None)))
         .unwrap();
     // create a new cache
@@ -297,8 +304,78 @@ fn test_join_categorical() {
 
     df_b.try_apply("bar", |s| s.cast(&DataType::Categorical(None)))
         .unwrap();
-    let out = df_a.join(&df_b, ["b"], ["bar"], JoinType::Left, None);
-    assert!(out.is_err());
+    for jt in [JoinType::Left, JoinType::Inner, JoinType::Outer] {
+        let expected = df_a_utf8
+            .join(&df_b_utf8, ["b"], ["bar"], jt.clone(), None)
+            .unwrap()
+            .sort(["b", "c", "ham"], false)
+            .unwrap();
+        let mut out = df_a.join(&df_b, ["b"], ["bar"], jt, None).unwrap();
+        assert!(matches!(
+            out.column("b").unwrap().dtype(),
+            DataType::Categorical(_)
+        ));
+        out.try_apply("b", |s| s.cast(&DataType::Utf8)).unwrap();
+        let out = out.sort(["b", "c", "ham"], false).unwrap();
+        assert!(out.frame_equal_missing(&expected));
+    }
+    let mask = df_a
+        .column("b")
+        .unwrap()
+        .slice(0, 3)
+        .equal(df_b.column("bar").unwrap())
+        .unwrap();
+    assert_eq!(Vec::from(&mask), &[Some(true), Some(false), Some(true)]);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "dtype-categorical")]
+fn test_join_categorical_global_left_local_right() {
+    let _lock = polars_core::SINGLE_LOCK.lock();
+    let (df_a, df_b) = get_dfs();
+    reset_string_cache();
+    // the right key is cast without a string cache, so it gets a local rev map
+    let mut df_b_cat = df_b.clone();
+    df_b_cat
+        .try_apply("bar", |s| s.cast(&DataType::Categorical(None)))
+        .unwrap();
+    let _sc = IUseStringCache::new();
+    let mut df_a_cat = df_a.clone();
+    df_a_cat
+        .try_apply("b", |s| s.cast(&DataType::Categorical(None)))
+        .unwrap();
+    let left_rev_map = df_a_cat
+        .column("b")
+        .unwrap()
+        .categorical()
+        .unwrap()
+        .get_rev_map()
+        .clone();
+    assert!(matches!(&*left_rev_map, RevMapping::Global(..)));
+
+    for jt in [JoinType::Left, JoinType::Inner, JoinType::Outer] {
+        let expected = df_a
+            .join(&df_b, ["b"], ["bar"], jt.clone(), None)
+            .unwrap()
+            .sort(["b", "c", "ham"], false)
+            .unwrap();
+        let out = df_a_cat.join(&df_b_cat, ["b"], ["bar"], jt, None).unwrap();
+        // the key keeps the global string cache of the left frame
+        let rev_map = out
+            .column("b")
+            .unwrap()
+            .categorical()
+            .unwrap()
+            .get_rev_map();
+        assert!(matches!(&**rev_map, RevMapping::Global(..)));
+        assert!(rev_map.same_src(&left_rev_map));
+
+        let mut out = out;
+        out.try_apply("b", |s| s.cast(&DataType::Utf8)).unwrap();
+        let out = out.sort(["b", "c", "ham"], false).unwrap();
+        assert!(out.frame_equal_missing(&expected));
+    }
 }
 
 #[test]
@@ -318,7 +395,8 @@ fn empty_df_join() -> PolarsResult<()> {
     .unwrap();
 
     let out = empty_df.inner_join(&df, ["key"], ["key"]).unwrap();
-    assert_eq!(out.height(), 0);
+    assert_eq!(out.shape(), (0, 3));
+    assert_eq!(out.get_column_names(), &["key", "eval", "aval"]);
     let out = empty_df.left_join(&df, ["key"], ["key"]).unwrap();
     assert_eq!(out.height(), 0);
     let out = empty_df.outer_join(&df, ["key"], ["key"]).unwrap();
@@ -390,13 +468,18 @@ fn test_join_err() -> PolarsResult<()> {
     ]?;
 
     // dtypes don't match, error
-    assert!(df1
-        .join(&df2, vec!["a", "b"], vec!["a", "b"], JoinType::Left, None)
-        .is_err());
+    let err = df1.join(&df2, vec!["a", "b"], vec!["a", "b"], JoinType::Left, None);
+    assert!(matches!(err, Err(PolarsError::SchemaMismatch(_))));
     // length of join keys don't match error
-    assert!(df1
-        .join(&df2, vec!["a"], vec!["a", "b"], JoinType::Left, None)
-        .is_err());
+    let err = df1.join(&df2, vec!["a"], vec!["a", "b"], JoinType::Left, None);
+    assert!(matches!(err, Err(PolarsError::ShapeMismatch(_))));
+    // a join key that doesn't exist
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Outer] {
+        let err = df1.join(&df2, vec!["c"], vec!["a"], how.clone(), None);
+        assert!(matches!(err, Err(PolarsError::ColumnNotFound(_))));
+        let err = df1.join(&df2, vec!["a"], vec!["c"], how, None);
+        assert!(matches!(err, Err(PolarsError::ColumnNotFound(_))));
+    }
     Ok(())
 }
 
@@ -614,3 +697,1159 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_inner_join_maintain_order() -> PolarsResult<()> {
+    // the left frame is the shorter relation, so the tuples are produced in the right frame's order
+    let left = df![
+        "days" => [3, 1, 2],
+        "temp" => [7.0, 22.1, 19.9]
+    ]?;
+    let right = df![
+        "days" => [1, 2, 3, 1, 2],
+        "rain" => [0.1, 0.2, 0.3, 0.4, 0.5]
+    ]?;
+
+    let args = JoinArgs::new(JoinType::Inner).with_maintain_order(true);
+    let out = left.join_with_args(&right, ["days"], ["days"], args.clone())?;
+    let expected = df![
+        "days" => [3, 1, 1, 2, 2],
+        "temp" => [7.0, 22.1, 22.1, 19.9, 19.9],
+        "rain" => [0.3, 0.1, 0.4, 0.2, 0.5]
+    ]?;
+    assert!(out.frame_equal(&expected));
+
+    // multiple keys
+    let left = left.hstack(&[Series::new("key", [0, 0, 0])])?;
+    let right = right.hstack(&[Series::new("key", [0, 0, 0, 0, 0])])?;
+    let out = left.join_with_args(&right, ["days", "key"], ["days", "key"], args)?;
+    assert_eq!(out.column("days")?, expected.column("days")?);
+    assert_eq!(out.column("rain")?, expected.column("rain")?);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_left_join_preserves_left_order() -> PolarsResult<()> {
+    let left = df![
+        "days" => [4, 0, 3, 1, 2, 1],
+        "temp" => [3., 22.1, 2., 19.9, 7., 18.3]
+    ]?;
+    let right = df![
+        "days" => [1, 2, 1, 4],
+        "rain" => [0.1, 0.2, 0.3, 0.4]
+    ]?;
+
+    for i in 1..8 {
+        std::env::set_var("POLARS_MAX_THREADS", format!("{}", i));
+        let out = left.left_join(&right, ["days"], ["days"])?;
+        let expected = df![
+            "days" => [4, 0, 3, 1, 1, 2, 1, 1],
+            "temp" => [3., 22.1, 2., 19.9, 19.9, 7., 18.3, 18.3],
+            "rain" => [Some(0.4), None, None, Some(0.1), Some(0.3), Some(0.2), Some(0.1), Some(0.3)]
+        ]?;
+        assert!(out.frame_equal_missing(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_inner_join_chunked_keys_with_nulls() -> PolarsResult<()> {
+    let mut left = df![
+        "a" => [Some(1), None, Some(2)],
+        "b" => [1, 2, 3]
+    ]?;
+    left.vstack_mut(&df![
+        "a" => [Some(3), Some(1), None],
+        "b" => [4, 5, 6]
+    ]?)?;
+    assert_eq!(left.n_chunks(), 2);
+    let right = df![
+        "a" => [Some(1), Some(3), None],
+        "c" => [7, 8, 9]
+    ]?;
+
+    let out = left.join_with_args(
+        &right,
+        ["a"],
+        ["a"],
+        JoinArgs::new(JoinType::Inner).with_maintain_order(true),
+    )?;
+    let expected = df![
+        "a" => [Some(1), None, Some(3), Some(1), None],
+        "b" => [1, 2, 4, 5, 6],
+        "c" => [7, 9, 8, 7, 9]
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_chained_join_suffix_collision() -> PolarsResult<()> {
+    let (temp, rain) = create_frames();
+    let rain_2 = df![
+        "days" => [0, 1, 2],
+        "rain" => [0.5, 0.6, 0.7]
+    ]?;
+
+    let out = temp
+        .left_join(&rain, ["days"], ["days"])?
+        .left_join(&rain_2, ["days"], ["days"])?;
+    assert_eq!(
+        out.get_column_names(),
+        &["days", "temp", "rain", "rain_right", "rain_right_1"]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_empty_right() -> PolarsResult<()> {
+    let (temp, rain) = create_frames();
+    let rain = rain.clear();
+
+    let out = temp.inner_join(&rain, ["days"], ["days"])?;
+    assert_eq!(out.shape(), (0, 4));
+    assert_eq!(
+        out.get_column_names(),
+        &["days", "temp", "rain", "rain_right"]
+    );
+
+    let out = temp.left_join(&rain, ["days"], ["days"])?;
+    assert_eq!(out.shape(), (3, 4));
+    assert_eq!(out.column("rain_right")?.null_count(), 3);
+    assert!(out.select(["days", "temp", "rain"])?.frame_equal(&temp));
+
+    let out = temp.outer_join(&rain, ["days"], ["days"])?;
+    assert_eq!(out.shape(), (3, 4));
+    assert_eq!(out.column("days")?, temp.column("days")?);
+    assert_eq!(out.column("rain_right")?.null_count(), 3);
+
+    // multiple keys
+    let out = temp.join(
+        &rain,
+        ["days", "rain"],
+        ["days", "rain"],
+        JoinType::Left,
+        None,
+    )?;
+    assert_eq!(out.shape(), (3, 3));
+    let out = temp.join(
+        &rain,
+        ["days", "rain"],
+        ["days", "rain"],
+        JoinType::Outer,
+        None,
+    )?;
+    assert_eq!(out.shape(), (3, 3));
+    let out = temp.join(
+        &rain,
+        ["days", "rain"],
+        ["days", "rain"],
+        JoinType::Inner,
+        None,
+    )?;
+    assert_eq!(out.shape(), (0, 3));
+
+    #[cfg(feature = "semi_anti_join")]
+    {
+        let out = temp.join(&rain, ["days"], ["days"], JoinType::Semi, None)?;
+        assert_eq!(out.shape(), (0, 3));
+        let out = temp.join(&rain, ["days"], ["days"], JoinType::Anti, None)?;
+        assert!(out.frame_equal(&temp));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "semi_anti_join")]
+fn test_semi_anti_join_multiple_keys() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 1, 2, 2, 3],
+        "kind" => [Some("a"), Some("b"), Some("a"), None, Some("a")],
+        "val" => [0, 1, 2, 3, 4]
+    ]?;
+    let right = df![
+        "id" => [1, 2, 2, 4],
+        "kind" => [Some("b"), Some("a"), None, Some("a")]
+    ]?;
+
+    let semi = left.join(&right, ["id", "kind"], ["id", "kind"], JoinType::Semi, None)?;
+    let anti = left.join(&right, ["id", "kind"], ["id", "kind"], JoinType::Anti, None)?;
+    assert_eq!(semi.get_column_names(), &["id", "kind", "val"]);
+    assert_eq!(semi.height() + anti.height(), left.height());
+    // a composite key only matches if all its columns match
+    let semi_vals = semi.column("val")?.i32()?;
+    assert!(semi_vals.into_no_null_iter().all(|v| v != 0 && v != 4));
+    assert_eq!(Vec::from(anti.column("val")?.i32()?), &[Some(0), Some(4)]);
+
+    // nulls in the keys match like they do in the other joins
+    let inner = left
+        .join(
+            &right,
+            ["id", "kind"],
+            ["id", "kind"],
+            JoinType::Inner,
+            None,
+        )?
+        .sort(["val"], false)?;
+    assert!(semi.frame_equal_missing(&inner.select(["id", "kind", "val"])?));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_outer_join_hash_seed() -> PolarsResult<()> {
+    let left = df![
+        "a" => (0..50).collect::<Vec<i32>>(),
+        "b" => (0..50).map(|v| v * 2).collect::<Vec<i32>>()
+    ]?;
+    let right = df![
+        "a" => (25..100).collect::<Vec<i32>>(),
+        "b" => (25..100).map(|v| v * 2).collect::<Vec<i32>>(),
+        "c" => (25..100).map(|v| v * 3).collect::<Vec<i32>>()
+    ]?;
+
+    for on in [vec!["a"], vec!["a", "b"]] {
+        let args = JoinArgs::new(JoinType::Outer).with_hash_seed(Some(42));
+        let out = left.join_with_args(&right, &on, &on, args.clone())?;
+        let out_2 = left.join_with_args(&right, &on, &on, args)?;
+        assert!(out.frame_equal_missing(&out_2));
+
+        // the seed does not change which rows match
+        let unseeded = left.join(&right, &on, &on, JoinType::Outer, None)?;
+        assert!(out
+            .sort(["a"], false)?
+            .frame_equal_missing(&unseeded.sort(["a"], false)?));
+        assert_eq!(out.height(), 100);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_outer_join_column_order() -> PolarsResult<()> {
+    let left = df![
+        "temp" => [1.0, 2.0, 3.0],
+        "days" => [1, 2, 3],
+        "rain" => [0.1, 0.2, 0.3]
+    ]?;
+    let right = df![
+        "days" => [3, 4],
+        "temp" => [30.0, 40.0],
+        "wind" => [5, 6]
+    ]?;
+
+    let out = left.outer_join(&right, ["days"], ["days"])?;
+    assert_eq!(
+        out.get_column_names(),
+        &["days", "temp", "rain", "temp_right", "wind"]
+    );
+    let days = out.column("days")?.sort(false);
+    assert_eq!(
+        Vec::from(days.i32()?),
+        &[Some(1), Some(2), Some(3), Some(4)]
+    );
+
+    let out = left.outer_join(&right, ["days", "temp"], ["days", "temp"])?;
+    assert_eq!(out.get_column_names(), &["days", "temp", "rain", "wind"]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_fill_null_joined_frame() -> PolarsResult<()> {
+    let left = df![
+        "day" => [1, 2, 3, 4],
+        "store" => ["a", "b", "c", "d"]
+    ]?;
+    let right = df![
+        "day" => [1, 3],
+        "sales" => [10.0, 30.0],
+        "manager" => ["x", "y"]
+    ]?;
+    let joined = left.left_join(&right, ["day"], ["day"])?;
+
+    let out = joined.fill_null(FillNullStrategy::Forward(None))?;
+    assert_eq!(
+        Vec::from(out.column("sales")?.f64()?),
+        &[Some(10.0), Some(10.0), Some(30.0), Some(30.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("manager")?.utf8()?),
+        &[Some("x"), Some("x"), Some("y"), Some("y")]
+    );
+
+    // only the subset is filled
+    let out = joined.fill_null_subset(FillNullStrategy::Forward(None), &["sales"])?;
+    assert_eq!(out.column("sales")?.null_count(), 0);
+    assert_eq!(out.column("manager")?.null_count(), 2);
+
+    // the mean can't be taken of utf8, so that column is skipped
+    let out = joined.fill_null(FillNullStrategy::Mean)?;
+    assert_eq!(
+        Vec::from(out.column("sales")?.f64()?),
+        &[Some(10.0), Some(20.0), Some(30.0), Some(20.0)]
+    );
+    assert_eq!(out.column("manager")?.null_count(), 2);
+
+    // unless the column is requested explicitly
+    assert!(joined
+        .fill_null_subset(FillNullStrategy::Mean, &["sales", "manager"])
+        .is_err());
+    assert!(joined
+        .fill_null_subset(FillNullStrategy::Zero, &["missing"])
+        .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_validation() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 2, 3, 2],
+        "name" => ["a", "b", "c", "d"]
+    ]?;
+    let right = df![
+        "id" => [1, 2, 3, 3],
+        "kind" => ["x", "y", "z", "w"],
+        "value" => [1, 2, 3, 4]
+    ]?;
+
+    let args = JoinArgs::new(JoinType::Left).with_validation(JoinValidation::ManyToOne);
+    let err = left
+        .join_with_args(&right, ["id"], ["id"], args)
+        .unwrap_err();
+    assert!(err.to_string().contains("right frame"));
+    assert!(err.to_string().contains("key 3 occurs more than once"));
+
+    let args = JoinArgs::new(JoinType::Inner).with_validation(JoinValidation::OneToMany);
+    let err = left
+        .join_with_args(&right, ["id"], ["id"], args)
+        .unwrap_err();
+    assert!(err.to_string().contains("key 2 occurs more than once"));
+
+    // with multiple keys the combination of the values has to be unique
+    let args = JoinArgs::new(JoinType::Inner).with_validation(JoinValidation::OneToOne);
+    let out = right.join_with_args(&right, ["id", "kind"], ["id", "kind"], args.clone())?;
+    assert_eq!(out.height(), 4);
+    let left = df![
+        "id" => [1, 2, 1],
+        "kind" => ["x", "y", "x"]
+    ]?;
+    let err = left
+        .join_with_args(&right, ["id", "kind"], ["id", "kind"], args)
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("key (1, \"x\") occurs more than once"));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_drop_keys() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 2, 3],
+        "a" => ["x", "y", "z"]
+    ]?;
+    let right = df![
+        "key" => [2, 3, 4],
+        "b" => [20, 30, 40]
+    ]?;
+
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Outer] {
+        let args = JoinArgs::new(how.clone()).with_drop_keys(true);
+        let out = left.join_with_args(&right, ["id"], ["key"], args)?;
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+
+        // the rows are the same as those of the join that keeps the key
+        let expected = left.join(&right, ["id"], ["key"], how, None)?.drop("id")?;
+        assert!(out.frame_equal_missing(&expected));
+    }
+
+    let right = df![
+        "key" => [2, 3, 4],
+        "name" => ["y", "q", "w"],
+        "b" => [20, 30, 40]
+    ]?;
+    let out = left.join_with_args(
+        &right,
+        ["id", "a"],
+        ["key", "name"],
+        JoinArgs::new(JoinType::Outer).with_drop_keys(true),
+    )?;
+    assert_eq!(out.get_column_names(), &["b"]);
+    assert_eq!(out.height(), 5);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_left_join_fill() -> PolarsResult<()> {
+    let left = df![
+        "days" => [0, 1, 2],
+        "temp" => [22.1, 19.9, 7.]
+    ]?;
+    let right = df![
+        "days" => [1, 2],
+        "count" => [Some(5), None]
+    ]?;
+
+    let out = left.left_join_fill(&right, ["days"], ["days"], AnyValue::Int32(0))?;
+    // only the unmatched row is filled, the null of the right frame is kept
+    let expected = df![
+        "days" => [0, 1, 2],
+        "temp" => [22.1, 19.9, 7.],
+        "count" => [Some(0), Some(5), None]
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+
+    // the fill value is cast to the column's dtype
+    let out = left.left_join_fill(&right, ["days"], ["days"], AnyValue::Float64(1.0))?;
+    assert_eq!(out.column("count")?.dtype(), &DataType::Int32);
+    assert_eq!(out.column("count")?.i32()?.get(0), Some(1));
+
+    // or an error is raised if it can't be represented
+    assert!(left
+        .left_join_fill(&right, ["days"], ["days"], AnyValue::Utf8("a"))
+        .is_err());
+    // a fractional value would be truncated
+    assert!(left
+        .left_join_fill(&right, ["days"], ["days"], AnyValue::Float64(1.5))
+        .is_err());
+
+    // but a float is rounded to a narrower float column
+    let right = df![
+        "days" => [1, 2],
+        "rain" => [0.5f32, 0.25]
+    ]?;
+    let out = left.left_join_fill(&right, ["days"], ["days"], AnyValue::Float64(0.1))?;
+    assert_eq!(out.column("rain")?.f32()?.get(0), Some(0.1f32));
+
+    // every right column is filled, whatever its name
+    let right = df![
+        "days" => [1, 2],
+        "__POLARS_JOIN_MATCHED" => [Some(true), None]
+    ]?;
+    let out = left.left_join_fill(&right, ["days"], ["days"], AnyValue::Boolean(false))?;
+    assert_eq!(
+        Vec::from(out.column("__POLARS_JOIN_MATCHED")?.bool()?),
+        &[Some(false), Some(true), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_indices() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 2, 3],
+        "a" => ["x", "y", "z"]
+    ]?;
+    let right = df![
+        "id" => [3, 1, 4],
+        "b" => [30, 10, 40]
+    ]?;
+
+    let args = JoinArgs::new(JoinType::Inner).with_maintain_order(true);
+    let (idx_left, idx_right) = left.join_indices(&right, ["id"], ["id"], args)?;
+    assert_eq!(Vec::from(&idx_left), &[Some(0), Some(2)]);
+    assert_eq!(Vec::from(&idx_right), &[Some(1), Some(0)]);
+
+    let args = JoinArgs::new(JoinType::Left);
+    let (idx_left, idx_right) = left.join_indices(&right, ["id"], ["id"], args)?;
+    assert_eq!(Vec::from(&idx_left), &[Some(0), Some(1), Some(2)]);
+    assert_eq!(Vec::from(&idx_right), &[Some(1), None, Some(0)]);
+
+    // the indices select the same rows as the join itself
+    let joined = left
+        .take(&idx_left)?
+        .hstack(right.drop("id")?.take(&idx_right)?.get_columns())?;
+    let expected = left.left_join(&right, ["id"], ["id"])?;
+    assert!(joined.frame_equal_missing(&expected));
+
+    let args = JoinArgs::new(JoinType::Outer);
+    let (idx_left, idx_right) = left.join_indices(&right, ["id", "id"], ["id", "id"], args)?;
+    let mut pairs = idx_left
+        .into_iter()
+        .zip(idx_right.into_iter())
+        .collect::<Vec<_>>();
+    pairs.sort();
+    assert_eq!(
+        pairs,
+        &[
+            (None, Some(2)),
+            (Some(0), Some(1)),
+            (Some(1), None),
+            (Some(2), Some(0))
+        ]
+    );
+
+    let args = JoinArgs::new(JoinType::Cross);
+    assert!(left.join_indices(&right, ["id"], ["id"], args).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "cross_join")]
+fn test_join_cross_without_keys() -> PolarsResult<()> {
+    let left = df!["a" => [1, 2, 3]]?;
+    let right = df!["b" => ["x", "y"]]?;
+    let no_keys = Vec::<&str>::new();
+
+    let out = left.join(&right, &no_keys, &no_keys, JoinType::Cross, None)?;
+    assert_eq!(out.shape(), (6, 2));
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(1), Some(2), Some(2), Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.utf8()?),
+        &[
+            Some("x"),
+            Some("y"),
+            Some("x"),
+            Some("y"),
+            Some("x"),
+            Some("y")
+        ]
+    );
+
+    let err = left.join(&right, ["a"], ["b"], JoinType::Cross, None);
+    assert!(matches!(err, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "cross_join")]
+fn test_cross_join_max_rows() -> PolarsResult<()> {
+    let left = df!["a" => [1, 2, 3]]?;
+    let right = df!["b" => ["x", "y"]]?;
+    let no_keys = Vec::<&str>::new();
+
+    let args = JoinArgs::new(JoinType::Cross).with_cross_join_max_rows(Some(5));
+    let err = left.join_with_args(&right, &no_keys, &no_keys, args);
+    assert!(matches!(err, Err(PolarsError::ComputeError(_))));
+
+    let args = JoinArgs::new(JoinType::Cross).with_cross_join_max_rows(Some(6));
+    let out = left.join_with_args(&right, &no_keys, &no_keys, args)?;
+    assert_eq!(out.shape(), (6, 2));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_u64_keys() -> PolarsResult<()> {
+    let big = u32::MAX as u64 + 1;
+    let left = df![
+        "key" => [1u64, big, big + 1],
+        "a" => [1, 2, 3]
+    ]?;
+    let right = df![
+        "key" => [big + 1, 1u64, 7u64],
+        "b" => [30, 10, 70]
+    ]?;
+    let out = left.join(&right, ["key"], ["key"], JoinType::Left, None)?;
+    assert_eq!(out.column("key")?.dtype(), &DataType::UInt64);
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(10), None, Some(30)]
+    );
+
+    let out = left.join(&right, ["key"], ["key"], JoinType::Outer, None)?;
+    assert_eq!(out.column("key")?.dtype(), &DataType::UInt64);
+    assert_eq!(out.height(), 4);
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_inner_join_nulls_one_side() -> PolarsResult<()> {
+    // large enough to not take the nested loop path
+    let left = df![
+        "key" => (0i64..200).collect::<Vec<_>>(),
+        "a" => (0i32..200).collect::<Vec<_>>()
+    ]?;
+    let right_keys = (0i64..300)
+        .rev()
+        .map(|v| if v % 3 == 0 { None } else { Some(v) })
+        .collect::<Vec<_>>();
+    let right = df![
+        "key" => right_keys,
+        "b" => (0i32..300).collect::<Vec<_>>()
+    ]?;
+
+    for (l, r) in [(&left, &right), (&right, &left)] {
+        let out = l.inner_join(r, ["key"], ["key"])?;
+        // keys 0..200 that are not a multiple of 3
+        assert_eq!(out.height(), 133);
+        let out = out.sort(["key"], false)?;
+        let keys = out.column("key")?.i64()?;
+        assert_eq!(keys.null_count(), 0);
+        assert_eq!(keys.get(0), Some(1));
+        let a = out.column("a")?.i32()?;
+        let b = out.column("b")?.i32()?;
+        for i in 0..out.height() {
+            let key = keys.get(i).unwrap();
+            assert_eq!(a.get(i), Some(key as i32));
+            assert_eq!(b.get(i), Some(299 - key as i32));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(all(
+    feature = "dtype-i8",
+    feature = "dtype-i16",
+    feature = "dtype-u8",
+    feature = "dtype-u16"
+))]
+fn test_join_compact_integer_keys() -> PolarsResult<()> {
+    for dtype in [
+        DataType::Int8,
+        DataType::Int16,
+        DataType::UInt8,
+        DataType::UInt16,
+    ] {
+        let mut left = df![
+            "key" => [0, 1, 3],
+            "a" => [1, 2, 3]
+        ]?;
+        let mut right = df![
+            "key" => [3, 0, 5],
+            "b" => [30, 10, 50]
+        ]?;
+        left.apply("key", |s| s.cast(&dtype).unwrap())?;
+        right.apply("key", |s| s.cast(&dtype).unwrap())?;
+
+        let out = left.join(&right, ["key"], ["key"], JoinType::Left, None)?;
+        assert_eq!(out.column("key")?.dtype(), &dtype);
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(10), None, Some(30)]
+        );
+        let out = left.join(&right, ["key"], ["key"], JoinType::Outer, None)?;
+        assert_eq!(out.column("key")?.dtype(), &dtype);
+        assert_eq!(out.height(), 4);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_coalesce_key() -> PolarsResult<()> {
+    let left = df![
+        "id" => [1, 2, 3],
+        "a" => ["x", "y", "z"]
+    ]?;
+    let right = df![
+        "id" => [3, 1, 4],
+        "b" => [30, 10, 40]
+    ]?;
+
+    let args = JoinArgs::new(JoinType::Left).with_coalesce_key(false);
+    let out = left.join_with_args(&right, ["id"], ["id"], args)?;
+    assert_eq!(out.get_column_names(), &["id", "a", "id_right", "b"]);
+    assert_eq!(
+        Vec::from(out.column("id_right")?.i32()?),
+        &[Some(1), None, Some(3)]
+    );
+
+    let args = JoinArgs::new(JoinType::Outer).with_coalesce_key(false);
+    let out = left
+        .join_with_args(&right, ["id"], ["id"], args)?
+        .sort(["id_right"], false)?;
+    assert_eq!(out.get_column_names(), &["id", "a", "id_right", "b"]);
+    assert_eq!(
+        Vec::from(out.column("id")?.i32()?),
+        &[Some(2), Some(1), Some(3), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("id_right")?.i32()?),
+        &[None, Some(1), Some(3), Some(4)]
+    );
+
+    // the default coalesces the keys into the left key column
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Outer] {
+        let out = left.join_with_args(&right, ["id"], ["id"], JoinArgs::new(how))?;
+        assert_eq!(out.get_column_names(), &["id", "a", "b"]);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_explosion_factor() -> PolarsResult<()> {
+    // every row matches every row of the other frame
+    let left = df![
+        "key" => [1, 1, 1, 1],
+        "a" => [1, 2, 3, 4]
+    ]?;
+    let right = df![
+        "key" => [1, 1, 1],
+        "b" => [1, 2, 3]
+    ]?;
+
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Outer] {
+        let args = JoinArgs::new(how.clone()).with_explosion_factor(Some(2.0));
+        assert!(left.join_with_args(&right, ["key"], ["key"], args).is_err());
+        let args = JoinArgs::new(how.clone()).with_explosion_factor(Some(2.0));
+        assert!(left
+            .join_with_args(&right, ["key", "key"], ["key", "key"], args)
+            .is_err());
+
+        // 12 rows are within three times the 4 rows of the left frame
+        let args = JoinArgs::new(how).with_explosion_factor(Some(3.0));
+        let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+        assert_eq!(out.height(), 12);
+    }
+
+    let args = JoinArgs::new(JoinType::Inner).with_explosion_factor(Some(2.0));
+    assert!(left.join_indices(&right, ["key"], ["key"], args).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_outer_join_nulls_not_equal() -> PolarsResult<()> {
+    let left = df![
+        "key" => [Some(1), None, Some(2)],
+        "a" => [1, 2, 3]
+    ]?;
+    let right = df![
+        "key" => [None, Some(1), None],
+        "b" => [10, 20, 30]
+    ]?;
+
+    // the left null matches both right nulls
+    let out = left.join(&right, ["key"], ["key"], JoinType::Outer, None)?;
+    assert_eq!(out.height(), 4);
+
+    // each null is a row of its own
+    let args = JoinArgs::new(JoinType::Outer).with_join_nulls(false);
+    let out = left
+        .join_with_args(&right, ["key"], ["key"], args.clone())?
+        .sort(["a", "b"], false)?;
+    assert_eq!(out.height(), 5);
+    assert_eq!(out.column("key")?.null_count(), 3);
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[None, None, Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(10), Some(30), Some(20), None, None]
+    );
+
+    // the same holds for multiple keys and without coalescing
+    let out = left.join_with_args(&right, ["key", "key"], ["key", "key"], args.clone())?;
+    assert_eq!(out.height(), 5);
+    let out = left.join_with_args(&right, ["key"], ["key"], args.with_coalesce_key(false))?;
+    assert_eq!(out.height(), 5);
+
+    for (how, height) in [(JoinType::Inner, 1), (JoinType::Left, 3)] {
+        let args = JoinArgs::new(how.clone()).with_join_nulls(false);
+        let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+        assert_eq!(out.height(), height);
+        let args = JoinArgs::new(how).with_join_nulls(false);
+        let (idx_left, _) = left.join_indices(&right, ["key"], ["key"], args)?;
+        assert_eq!(idx_left.len(), height);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_boolean_keys() -> PolarsResult<()> {
+    let left = df![
+        "key" => [Some(true), Some(false), None, Some(true)],
+        "a" => [1, 2, 3, 4]
+    ]?;
+    let right = df![
+        "key" => [Some(true), None, None],
+        "b" => [10, 20, 30]
+    ]?;
+
+    // the left null matches both right nulls
+    let out = left
+        .inner_join(&right, ["key"], ["key"])?
+        .sort(["a", "b"], false)?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(3), Some(3), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(10), Some(20), Some(30), Some(10)]
+    );
+    assert_eq!(
+        Vec::from(out.column("key")?.bool()?),
+        &[Some(true), None, None, Some(true)]
+    );
+
+    // `false` has no match
+    for how in [JoinType::Left, JoinType::Outer] {
+        let out = left
+            .join(&right, ["key"], ["key"], how, None)?
+            .sort(["a", "b"], false)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3), Some(3), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(10), None, Some(20), Some(30), Some(10)]
+        );
+        assert_eq!(
+            Vec::from(out.column("key")?.bool()?),
+            &[Some(true), Some(false), None, None, Some(true)]
+        );
+    }
+
+    // null keys don't match if nulls aren't joined
+    let args = JoinArgs::new(JoinType::Inner).with_join_nulls(false);
+    let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+    assert_eq!(out.height(), 2);
+    let args = JoinArgs::new(JoinType::Outer).with_join_nulls(false);
+    let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+    assert_eq!(out.height(), 6);
+    assert_eq!(out.column("key")?.null_count(), 3);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_series() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1, 2, 3, 2],
+        "a" => ["w", "x", "y", "z"]
+    ]?;
+    let ids = Series::new("id", [2, 3, 4]);
+    let names = Series::new("name", ["two", "three", "four"]);
+
+    let out = df.join_series(&ids, &names, "id", JoinType::Left)?;
+    assert_eq!(out.get_column_names(), &["id", "a", "name"]);
+    assert_eq!(
+        Vec::from(out.column("name")?.utf8()?),
+        &[None, Some("two"), Some("three"), Some("two")]
+    );
+
+    let out = df.join_series(&ids, &names, "id", JoinType::Inner)?;
+    assert_eq!(out.height(), 3);
+
+    let err = df.join_series(&ids, &names.slice(0, 2), "id", JoinType::Left);
+    assert!(matches!(err, Err(PolarsError::ShapeMismatch(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_map_keys() -> PolarsResult<()> {
+    let left = df![
+        "key" => ["a ", " b", "c"],
+        "x" => [1, 2, 3]
+    ]?;
+    let right = df![
+        "key" => ["b", "a", "d"],
+        "y" => [10, 20, 40]
+    ]?;
+    let trim = |s: &Series| s.utf8().unwrap().apply_mut(str::trim).into_series();
+
+    // the trimmed keys match, the output keeps the original keys
+    let args = JoinArgs::new(JoinType::Inner)
+        .with_maintain_order(true)
+        .map_keys(trim);
+    let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+    assert_eq!(out.get_column_names(), &["key", "x", "y"]);
+    assert_eq!(
+        Vec::from(out.column("key")?.utf8()?),
+        &[Some("a "), Some(" b")]
+    );
+    assert_eq!(Vec::from(out.column("y")?.i32()?), &[Some(20), Some(10)]);
+
+    let args = JoinArgs::new(JoinType::Outer).map_keys(trim);
+    let out = left
+        .join_with_args(&right, ["key"], ["key"], args)?
+        .sort(["key"], false)?;
+    assert_eq!(
+        Vec::from(out.column("key")?.utf8()?),
+        &[Some(" b"), Some("a "), Some("c"), Some("d")]
+    );
+    assert_eq!(
+        Vec::from(out.column("y")?.i32()?),
+        &[Some(10), Some(20), None, Some(40)]
+    );
+
+    // the mapped keys must have the same dtype on both sides
+    let left = df!["key" => [1i32, 2, 3]]?;
+    let right = df!["key" => [2i64, 3, 4]]?;
+    let args = JoinArgs::new(JoinType::Inner).map_keys(|s: &Series| s.clone());
+    let err = left.join_with_args(&right, ["key"], ["key"], args);
+    assert!(matches!(err, Err(PolarsError::SchemaMismatch(_))));
+
+    let args = JoinArgs::new(JoinType::Inner)
+        .with_maintain_order(true)
+        .map_keys(|s: &Series| s.cast(&DataType::Int64).unwrap());
+    let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+    assert_eq!(Vec::from(out.column("key")?.i32()?), &[Some(2), Some(3)]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_null_key_fill() -> PolarsResult<()> {
+    let left = df![
+        "key" => [Some(1i64), None, Some(0)],
+        "a" => [Some("x"), None, Some("z")]
+    ]?;
+    let right = df![
+        "key" => [Some(0i64), Some(1), None],
+        "b" => [Some(10), Some(20), None]
+    ]?;
+
+    let args = JoinArgs::new(JoinType::Inner)
+        .with_maintain_order(true)
+        .with_null_key_fill(Some(AnyValue::Int64(0)));
+    let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+    // the null keys match each other and the sentinel rows
+    let expected = df![
+        "key" => [1i64, 0, 0, 0, 0],
+        "a" => [Some("x"), None, None, Some("z"), Some("z")],
+        "b" => [Some(20), Some(10), None, Some(10), None]
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+
+    // null keys also match if null keys don't match each other
+    let args = JoinArgs::new(JoinType::Inner)
+        .with_join_nulls(false)
+        .with_null_key_fill(Some(AnyValue::Int64(0)));
+    let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+    assert_eq!(out.height(), 5);
+
+    // the value is cast to the dtype of the keys
+    let args = JoinArgs::new(JoinType::Left).with_null_key_fill(Some(AnyValue::Int32(-1)));
+    let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+    assert_eq!(out.column("key")?.dtype(), &DataType::Int64);
+    assert_eq!(out.column("key")?.null_count(), 0);
+    assert_eq!(out.column("a")?.null_count(), 1);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_on() -> PolarsResult<()> {
+    let temp = df![
+        "days" => [0, 1, 2, 3, 4],
+        "temp" => [22.1, 19.9, 7., 2., 3.]
+    ]?;
+    let rain = df![
+        "days" => [1, 2],
+        "rain" => [0.1, 0.2]
+    ]?;
+
+    let out = temp.join_on(&rain, ["days"], JoinType::Left)?;
+    assert!(out.frame_equal_missing(&temp.left_join(&rain, ["days"], ["days"])?));
+    assert!(out.frame_equal_missing(&temp.left_join_on(&rain, "days")?));
+    assert_eq!(out.get_column_names(), &["days", "temp", "rain"]);
+
+    let out = temp.inner_join_on(&rain, "days")?;
+    assert_eq!(out.get_column_names(), &["days", "temp", "rain"]);
+    assert_eq!(Vec::from(out.column("days")?.i32()?), &[Some(1), Some(2)]);
+
+    // the keys are coalesced into a single column
+    let out = temp.outer_join_on(&rain, "days")?.sort(["days"], false)?;
+    assert_eq!(out.get_column_names(), &["days", "temp", "rain"]);
+    assert_eq!(out.column("days")?.null_count(), 0);
+    assert_eq!(out.column("rain")?.null_count(), 3);
+
+    // multiple keys
+    let temp = df![
+        "days" => [0, 1, 1],
+        "site" => ["a", "a", "b"],
+        "temp" => [22.1, 19.9, 7.]
+    ]?;
+    let rain = df![
+        "days" => [1, 1],
+        "site" => ["b", "c"],
+        "rain" => [0.1, 0.2]
+    ]?;
+    let out = temp.join_on(&rain, ["days", "site"], JoinType::Inner)?;
+    assert_eq!(out.get_column_names(), &["days", "site", "temp", "rain"]);
+    assert_eq!(out.height(), 1);
+    assert_eq!(Vec::from(out.column("temp")?.f64()?), &[Some(7.)]);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_left_join_with_stats() -> PolarsResult<()> {
+    let temp = df![
+        "days" => [0, 1, 2, 3, 4],
+        "temp" => [22.1, 19.9, 7., 2., 3.]
+    ]?;
+    let rain = df![
+        "days" => [1, 2],
+        "rain" => [0.1, 0.2]
+    ]?;
+    let (joined, stats) = temp.left_join_with_stats(&rain, ["days"], ["days"])?;
+    assert!(joined.frame_equal_missing(&temp.left_join(&rain, ["days"], ["days"])?));
+    assert_eq!(
+        stats,
+        JoinStats {
+            left_rows: 5,
+            left_matched: 2,
+            right_matched: 2,
+            output_rows: 5,
+        }
+    );
+    assert_eq!(stats.left_match_rate(), Some(0.4));
+
+    let (joined, stats) = temp
+        .head(Some(0))
+        .left_join_with_stats(&rain, ["days"], ["days"])?;
+    assert_eq!(joined.height(), 0);
+    assert_eq!(stats.output_rows, 0);
+    assert_eq!(stats.left_match_rate(), None);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "dtype-categorical")]
+fn test_join_indices_categorical_sources() -> PolarsResult<()> {
+    let _lock = polars_core::SINGLE_LOCK.lock();
+    reset_string_cache();
+    let (df_a, df_b) = get_dfs();
+    // without a string cache every cast gets its own local rev map
+    let mut df_a_cat = df_a.clone();
+    df_a_cat.try_apply("b", |s| s.cast(&DataType::Categorical(None)))?;
+    let mut df_b_cat = df_b.clone();
+    df_b_cat.try_apply("bar", |s| s.cast(&DataType::Categorical(None)))?;
+
+    let (mut joined, stats) = df_a_cat.left_join_with_stats(&df_b_cat, ["b"], ["bar"])?;
+    let mut expected = df_a_cat.left_join(&df_b_cat, ["b"], ["bar"])?;
+    assert_eq!(stats.left_matched, 3);
+    assert_eq!(stats.output_rows, expected.height());
+    for df in [&mut joined, &mut expected] {
+        df.try_apply("b", |s| s.cast(&DataType::Utf8))?;
+    }
+    assert!(joined.frame_equal_missing(&expected));
+
+    let expected = df_a
+        .inner_join(&df_b, ["b"], ["bar"])?
+        .sort(["b", "c", "ham"], false)?;
+    for partitions in [1, 3] {
+        let mut out = df_a_cat.inner_join_partitioned(&df_b_cat, ["b"], ["bar"], partitions)?;
+        out.try_apply("b", |s| s.cast(&DataType::Utf8))?;
+        let out = out.sort(["b", "c", "ham"], false)?;
+        assert!(out.frame_equal_missing(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_left_join_match_order() -> PolarsResult<()> {
+    let left = df![
+        "key" => [1i64, 2, 1],
+        "key2" => ["a", "b", "a"]
+    ]?;
+    let right = df![
+        "key" => [1i64, 3, 1, 2, 1],
+        "key2" => ["a", "c", "a", "b", "a"],
+        "order" => [0i32, 1, 2, 3, 4]
+    ]?;
+
+    for keys in [vec!["key"], vec!["key", "key2"]] {
+        let args = JoinArgs::new(JoinType::Left);
+        let out = left.join_with_args(&right, keys.clone(), keys.clone(), args)?;
+        assert_eq!(
+            out.column("order")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[0, 2, 4, 3, 0, 2, 4]
+        );
+
+        let args = JoinArgs::new(JoinType::Left).with_reverse_matches(true);
+        let out = left.join_with_args(&right, keys.clone(), keys.clone(), args)?;
+        assert_eq!(
+            out.column("order")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            &[4, 2, 0, 3, 4, 2, 0]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_expected_size() -> PolarsResult<()> {
+    let left = df![
+        "key" => (0..1_000).map(|i| i % 50).collect::<Vec<i64>>(),
+        "a" => (0..1_000).collect::<Vec<i32>>()
+    ]?;
+    let right = df![
+        "key" => (0..200).map(|i| i % 80).collect::<Vec<i64>>(),
+        "b" => (0..200).collect::<Vec<i32>>()
+    ]?;
+
+    for how in [JoinType::Inner, JoinType::Left] {
+        let expected = left.join_with_args(&right, ["key"], ["key"], JoinArgs::new(how.clone()))?;
+        // a hint that is too small, too large or exact doesn't change the output
+        for expected_size in [0, 10, expected.height(), 1_000_000] {
+            let args = JoinArgs::new(how.clone()).with_expected_size(Some(expected_size));
+            let out = left.join_with_args(&right, ["key"], ["key"], args)?;
+            assert!(out.frame_equal(&expected));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_inner_join_partitioned() -> PolarsResult<()> {
+    let left = df![
+        "a" => (0i32..100).map(|i| i % 17).collect::<Vec<_>>(),
+        "b" => (0i32..100).map(|i| (i % 3).to_string()).collect::<Vec<_>>(),
+        "value" => (0i32..100).collect::<Vec<_>>()
+    ]?;
+    let right = df![
+        "a" => (0i32..60).map(|i| i % 23).collect::<Vec<_>>(),
+        "b" => (0i32..60).map(|i| (i % 2).to_string()).collect::<Vec<_>>(),
+        "value" => (0i32..60).collect::<Vec<_>>()
+    ]?;
+
+    for keys in [vec!["a"], vec!["a", "b"]] {
+        let expected = left
+            .inner_join(&right, keys.clone(), keys.clone())?
+            .sort(["value", "value_right"], false)?;
+        // more partitions than rows are clamped
+        for partitions in [1, 3, 8, usize::MAX] {
+            let out = left
+                .inner_join_partitioned(&right, keys.clone(), keys.clone(), partitions)?
+                .sort(["value", "value_right"], false)?;
+            assert!(out.frame_equal(&expected));
+        }
+    }
+
+    assert!(left
+        .inner_join_partitioned(&right, ["a"], ["a"], 0)
+        .is_err());
+    let empty: [&str; 0] = [];
+    let err = left.inner_join_partitioned(&right, empty, empty, 2);
+    assert!(matches!(err, Err(PolarsError::ShapeMismatch(_))));
+    Ok(())
+}
@@ -36,3 +36,398 @@ fn test_min_max_sorted_desc() {
     assert_eq!(a.max(), Some(4));
     assert_eq!(a.min(), Some(1));
 }
+
+#[test]
+fn test_series_sort() -> PolarsResult<()> {
+    let s = Series::new("a", &[Some(3), None, Some(1), Some(2)]);
+    let out = s.sort(false);
+    assert_eq!(Vec::from(out.i32()?), &[None, Some(1), Some(2), Some(3)]);
+    let out = s.sort_with(SortOptions {
+        descending: true,
+        nulls_last: true,
+        multithreaded: false,
+    });
+    assert_eq!(Vec::from(out.i32()?), &[Some(3), Some(2), Some(1), None]);
+
+    let s = Series::new("a", &["b", "c", "a"]);
+    let out = s.sort(true);
+    assert_eq!(Vec::from(out.utf8()?), &[Some("c"), Some("b"), Some("a")]);
+    Ok(())
+}
+
+#[test]
+fn test_series_sorted_flag() -> PolarsResult<()> {
+    let s = Series::new("a", &[3, 1, 2]);
+    assert_eq!(s.is_sorted_flag(), IsSorted::Not);
+
+    let mut asc = s.sort(false);
+    assert_eq!(asc.is_sorted_flag(), IsSorted::Ascending);
+    assert_eq!(s.sort(true).is_sorted_flag(), IsSorted::Descending);
+    assert_eq!(asc.reverse().is_sorted_flag(), IsSorted::Descending);
+
+    // appending may break the order, so the flag is cleared
+    asc.append(&Series::new("a", &[0]))?;
+    assert_eq!(asc.is_sorted_flag(), IsSorted::Not);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "search_sorted")]
+fn test_series_search_sorted() -> PolarsResult<()> {
+    let s = Series::new("a", &[1i64, 3, 3, 5, 8]);
+    // not flagged as sorted
+    assert!(s.search_sorted(AnyValue::Int64(3)).is_err());
+
+    let s = s.sort(false);
+    assert_eq!(s.search_sorted(AnyValue::Int64(0))?, 0);
+    assert_eq!(s.search_sorted(AnyValue::Int64(3))?, 1);
+    assert_eq!(s.search_sorted(AnyValue::Int64(4))?, 3);
+    assert_eq!(s.search_sorted(AnyValue::Int64(9))?, 5);
+    Ok(())
+}
+
+#[test]
+fn test_series_hist() -> PolarsResult<()> {
+    let s = Series::new(
+        "a",
+        &[Some(0i64), Some(1), None, Some(5), Some(9), Some(10)],
+    );
+    let out = s.hist(2)?;
+    let expected = df![
+        "lower_bound" => [0.0, 5.0],
+        "upper_bound" => [5.0, 10.0],
+        "count" => [2 as IdxSize, 3],
+    ]?;
+    assert!(out.frame_equal(&expected));
+
+    // all values equal results in a single bin
+    let s = Series::new("a", &[3i64, 3, 3]);
+    let out = s.hist(4)?;
+    assert_eq!(out.height(), 1);
+    assert_eq!(out.column("count")?.idx()?.get(0), Some(3));
+
+    assert!(s.hist(0).is_err());
+
+    // non-finite values are not counted
+    let s = Series::new(
+        "a",
+        &[f64::NAN, 0.0, f64::INFINITY, 1.0, f64::NEG_INFINITY, 10.0],
+    );
+    let out = s.hist(2)?;
+    let expected = df![
+        "lower_bound" => [0.0, 5.0],
+        "upper_bound" => [5.0, 10.0],
+        "count" => [2 as IdxSize, 1],
+    ]?;
+    assert!(out.frame_equal(&expected));
+    let s = Series::new("a", &[f64::NAN, f64::INFINITY]);
+    assert_eq!(s.hist(2)?.height(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "is_unique")]
+fn test_series_is_unique() -> PolarsResult<()> {
+    let s = Series::new("a", &[Some(1), Some(2), Some(1), None, Some(3), None]);
+    let unique = s.is_unique()?;
+    assert_eq!(
+        Vec::from(&unique),
+        &[
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false)
+        ]
+    );
+    let duplicated = s.is_duplicated()?;
+    assert!((&duplicated ^ &unique).all());
+
+    let s = Series::new("b", &["x", "y", "x"]);
+    assert_eq!(
+        Vec::from(&s.is_duplicated()?),
+        &[Some(true), Some(false), Some(true)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "unique_counts")]
+fn test_series_unique_counts() -> PolarsResult<()> {
+    let s = Series::new(
+        "a",
+        &[Some(3), Some(1), None, Some(3), Some(2), None, Some(3)],
+    );
+    let unique = s.unique_stable()?;
+    let counts = s.unique_counts();
+    assert_eq!(unique.len(), counts.len());
+    assert_eq!(Vec::from(unique.i32()?), &[Some(3), Some(1), None, Some(2)]);
+    assert_eq!(Vec::from(&counts), &[Some(3), Some(1), Some(2), Some(1)]);
+
+    let s = Series::new("b", &[true, false, false]);
+    assert_eq!(
+        Vec::from(s.unique_stable()?.bool()?),
+        &[Some(true), Some(false)]
+    );
+    assert_eq!(Vec::from(&s.unique_counts()), &[Some(1), Some(2)]);
+    Ok(())
+}
+
+#[test]
+fn test_series_shift_and_fill() -> PolarsResult<()> {
+    let s = Series::new("a", &[Some(1), Some(2), None, Some(4)]);
+
+    let out = s.shift_and_fill(2, AnyValue::Int32(0))?;
+    assert_eq!(out.name(), "a");
+    assert_eq!(Vec::from(out.i32()?), &[Some(0), Some(0), Some(1), Some(2)]);
+
+    let out = s.shift_and_fill(-1, AnyValue::Int32(0))?;
+    assert_eq!(Vec::from(out.i32()?), &[Some(2), None, Some(4), Some(0)]);
+
+    let out = s.shift_and_fill(-10, AnyValue::Int32(-1))?;
+    assert_eq!(Vec::from(out.i32()?), &[Some(-1); 4]);
+
+    let out = s.shift_and_fill(1, AnyValue::Null)?;
+    assert_eq!(Vec::from(out.i32()?), &[None, Some(1), Some(2), None]);
+
+    let err = s.shift_and_fill(1, AnyValue::Int64(0));
+    assert!(matches!(err, Err(PolarsError::SchemaMismatch(_))));
+
+    let s = Series::new("a", &["x", "y", "z"]);
+    let out = s.shift_and_fill(1, AnyValue::Utf8("w"))?;
+    assert_eq!(Vec::from(out.utf8()?), &[Some("w"), Some("x"), Some("y")]);
+
+    // logical dtypes are filled on their physical values
+    #[cfg(feature = "dtype-date")]
+    {
+        let s = Series::new("a", &[1i32, 2, 3]).cast(&DataType::Date)?;
+        let out = s.shift_and_fill(-1, AnyValue::Date(0))?;
+        assert_eq!(out.dtype(), &DataType::Date);
+        assert_eq!(
+            Vec::from(out.to_physical_repr().i32()?),
+            &[Some(2), Some(3), Some(0)]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_series_chunk_layout() -> PolarsResult<()> {
+    let mut s = Series::new("a", &[1, 2, 3]);
+    assert_eq!(s.n_chunks(), 1);
+
+    s.append(&Series::new("a", &[4, 5]))?;
+    assert_eq!(s.n_chunks(), 2);
+    assert_eq!(s.chunk_lengths().collect::<Vec<_>>(), &[3, 2]);
+    assert!(s.i32()?.cont_slice().is_err());
+
+    let s = s.rechunk();
+    assert_eq!(s.n_chunks(), 1);
+    assert_eq!(s.chunk_lengths().collect::<Vec<_>>(), &[5]);
+    assert_eq!(s.i32()?.cont_slice()?, &[1, 2, 3, 4, 5]);
+    Ok(())
+}
+
+#[test]
+fn test_series_float_masks() -> PolarsResult<()> {
+    let s = Series::new(
+        "a",
+        [
+            Some(1.0),
+            Some(f64::NAN),
+            Some(f64::INFINITY),
+            None,
+            Some(f64::NEG_INFINITY),
+        ],
+    );
+    let mask = |ca: BooleanChunked| ca.into_iter().collect::<Vec<_>>();
+    assert_eq!(
+        mask(s.is_nan()?),
+        &[Some(false), Some(true), Some(false), None, Some(false)]
+    );
+    assert_eq!(
+        mask(s.is_not_nan()?),
+        &[Some(true), Some(false), Some(true), None, Some(true)]
+    );
+    assert_eq!(
+        mask(s.is_finite()?),
+        &[Some(true), Some(false), Some(false), None, Some(false)]
+    );
+    assert_eq!(
+        mask(s.is_infinite()?),
+        &[Some(false), Some(false), Some(true), None, Some(true)]
+    );
+    let filtered = s.filter(&s.is_finite()?)?;
+    assert_eq!(
+        filtered.f64()?.into_iter().collect::<Vec<_>>(),
+        &[Some(1.0)]
+    );
+
+    let s = Series::new("a", [Some(1i32), None, Some(3)]);
+    assert_eq!(mask(s.is_nan()?), &[Some(false), None, Some(false)]);
+    assert_eq!(mask(s.is_not_nan()?), &[Some(true), None, Some(true)]);
+    assert_eq!(mask(s.is_finite()?), &[Some(true), None, Some(true)]);
+    assert_eq!(mask(s.is_infinite()?), &[Some(false), None, Some(false)]);
+    assert_eq!(s.is_finite()?.name(), "a");
+
+    let s = Series::new("a", ["x"]);
+    assert!(s.is_nan().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_series_scatter() -> PolarsResult<()> {
+    let s = Series::new("a", [1i64, 2, 3, 4, 5]);
+    let values = Series::new("b", [Some(20i64), None]);
+    let out = s.scatter(&[1, 3], &values)?;
+    assert_eq!(out.name(), "a");
+    assert_eq!(
+        out.i64()?.into_iter().collect::<Vec<_>>(),
+        &[Some(1), Some(20), Some(3), None, Some(5)]
+    );
+    // the input is not modified
+    assert_eq!(s.i64()?.cont_slice()?, &[1, 2, 3, 4, 5]);
+
+    assert!(matches!(
+        s.scatter(&[1, 5], &values),
+        Err(PolarsError::ComputeError(_))
+    ));
+    assert!(matches!(
+        s.scatter(&[1], &values),
+        Err(PolarsError::ShapeMismatch(_))
+    ));
+    assert!(matches!(
+        s.scatter(&[1, 2], &values.cast(&DataType::Int32)?),
+        Err(PolarsError::SchemaMismatch(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_series_arrow_round_trip() -> PolarsResult<()> {
+    let round_trip = |s: &Series| -> PolarsResult<()> {
+        let arr = s.to_arrow_array();
+        assert_eq!(arr.data_type(), &s.dtype().to_arrow());
+        let out = Series::from_arrow(s.name(), arr)?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.series_equal_missing(s));
+        Ok(())
+    };
+
+    round_trip(&Series::new("bool", [Some(true), None, Some(false)]))?;
+    round_trip(&Series::new("i32", [Some(1i32), None, Some(-3)]))?;
+    round_trip(&Series::new("i64", [Some(1i64), None, Some(-3)]))?;
+    round_trip(&Series::new("u32", [Some(1u32), None, Some(3)]))?;
+    round_trip(&Series::new("u64", [Some(1u64), None, Some(3)]))?;
+    round_trip(&Series::new("f32", [Some(1.5f32), None, Some(-3.0)]))?;
+    round_trip(&Series::new("f64", [Some(1.5f64), None, Some(-3.0)]))?;
+    round_trip(&Series::new("utf8", [Some("a"), None, Some("c")]))?;
+    round_trip(&Series::new(
+        "list",
+        [Series::new("", [1i32, 2]), Series::new("", [3i32])],
+    ))?;
+
+    let ints = Series::new("", [Some(1i64), None, Some(86_400_000)]);
+    #[cfg(feature = "dtype-date")]
+    round_trip(
+        &ints
+            .cast(&DataType::Int32)?
+            .cast(&DataType::Date)?
+            .with_name("date"),
+    )?;
+    #[cfg(feature = "dtype-datetime")]
+    for tu in [
+        TimeUnit::Nanoseconds,
+        TimeUnit::Microseconds,
+        TimeUnit::Milliseconds,
+    ] {
+        round_trip(
+            &ints
+                .cast(&DataType::Datetime(tu, None))?
+                .with_name("datetime"),
+        )?;
+    }
+    #[cfg(feature = "dtype-duration")]
+    round_trip(
+        &ints
+            .cast(&DataType::Duration(TimeUnit::Milliseconds))?
+            .with_name("duration"),
+    )?;
+    #[cfg(feature = "dtype-time")]
+    round_trip(&ints.cast(&DataType::Time)?.with_name("time"))?;
+
+    // a series with many chunks becomes a single array
+    let mut s = Series::new("a", [1i32, 2]);
+    s.append(&Series::new("a", [3i32]))?;
+    let arr = s.to_arrow_array();
+    assert_eq!(arr.len(), 3);
+    assert!(Series::from_arrow("a", arr)?.series_equal(&s));
+    Ok(())
+}
+
+#[test]
+fn test_series_compare_any_value() -> PolarsResult<()> {
+    let s = Series::new("a", [Some(1i32), None, Some(3)]);
+    let mask = s.gt(AnyValue::Null)?;
+    assert_eq!(mask.name(), "a");
+    assert_eq!(mask.len(), 3);
+    assert_eq!(mask.null_count(), 3);
+    assert_eq!(s.equal(AnyValue::Null)?.null_count(), 3);
+
+    let mask = s.gt(AnyValue::Int32(2))?;
+    assert_eq!(mask.name(), "a");
+    assert_eq!(
+        mask.into_iter().collect::<Vec<_>>(),
+        &[Some(false), None, Some(true)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_series_sum_mean_no_values() {
+    let all_null = Series::new("a", [None::<i32>, None]);
+    assert_eq!(all_null.sum::<i32>(), None);
+    assert_eq!(all_null.mean(), None);
+
+    let empty = Series::new_empty("a", &DataType::Float64);
+    assert_eq!(empty.sum::<f64>(), None);
+    assert_eq!(empty.mean(), None);
+
+    // a sum of zero is not missing
+    let s = Series::new("a", [Some(-1i32), None, Some(1)]);
+    assert_eq!(s.sum::<i32>(), Some(0));
+    assert_eq!(s.mean(), Some(0.0));
+}
+
+#[test]
+fn test_series_cast_bool_numeric() -> PolarsResult<()> {
+    let mask = Series::new("mask", [Some(true), Some(false), None, Some(true)]);
+    let n_true = mask
+        .bool()?
+        .into_iter()
+        .filter(|v| *v == Some(true))
+        .count();
+    for dtype in [DataType::Int32, DataType::UInt32] {
+        let s = mask.cast(&dtype)?;
+        assert_eq!(s.dtype(), &dtype);
+        assert_eq!(
+            Vec::from(s.cast(&DataType::Int32)?.i32()?),
+            &[Some(1), Some(0), None, Some(1)]
+        );
+        assert_eq!(s.sum::<usize>(), Some(n_true));
+    }
+
+    let s = Series::new("a", [Some(0i64), Some(3), None, Some(-1)]);
+    let mask = s.cast(&DataType::Boolean)?;
+    assert_eq!(
+        Vec::from(mask.bool()?),
+        &[Some(false), Some(true), None, Some(true)]
+    );
+    let s = Series::new("a", [0.0f64, 0.5]);
+    assert_eq!(
+        Vec::from(s.cast(&DataType::Boolean)?.bool()?),
+        &[Some(false), Some(true)]
+    );
+    Ok(())
+}
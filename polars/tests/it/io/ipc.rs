@@ -0,0 +1,33 @@
+use std::fs::File;
+
+use polars::prelude::*;
+
+#[test]
+fn test_ipc_memory_mapped_join() -> PolarsResult<()> {
+    let mut reference = df![
+        "key" => [1, 2, 3, 4],
+        "name" => ["a", "b", "c", "d"]
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_ipc_memory_mapped_join.ipc");
+    IpcWriter::new(File::create(&path)?).finish(&mut reference)?;
+
+    let mapped = IpcReader::new(File::open(&path)?)
+        .memory_mapped(true)
+        .finish()?;
+    assert!(mapped.frame_equal(&reference));
+
+    // the mapped frame is the build side, as it is the shorter relation
+    let df = df![
+        "key" => [4, 1, 5, 1, 2, 3],
+        "value" => [40, 10, 50, 11, 20, 30]
+    ]?;
+    let out = df.left_join(&mapped, ["key"], ["key"])?;
+    assert_eq!(
+        Vec::from(out.column("name")?.utf8()?),
+        &[Some("d"), Some("a"), None, Some("a"), Some("b"), Some("c")]
+    );
+
+    drop(mapped);
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
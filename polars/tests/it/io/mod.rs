@@ -6,6 +6,8 @@ mod json;
 #[cfg(feature = "parquet")]
 mod parquet;
 
+#[cfg(feature = "ipc")]
+mod ipc;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
 
@@ -1132,8 +1132,9 @@ impl PySeries {
         self.series.shrink_to_fit();
     }
 
-    pub fn dot(&self, other: &PySeries) -> Option<f64> {
-        self.series.dot(&other.series)
+    pub fn dot(&self, other: &PySeries) -> PyResult<f64> {
+        let out = self.series.dot(&other.series).map_err(PyPolarsErr::from)?;
+        Ok(out)
     }
 
     pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
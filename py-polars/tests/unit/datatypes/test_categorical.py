@@ -131,21 +131,18 @@ def test_unset_sorted_on_append() -> None:
         assert df.groupby("key").count()["count"].to_list() == [4, 4]
 
 
-def test_categorical_error_on_local_cmp() -> None:
+def test_categorical_local_cmp() -> None:
     df_cat = pl.DataFrame(
         [
             pl.Series("a_cat", ["c", "a", "b", "c", "b"], dtype=pl.Categorical),
-            pl.Series("b_cat", ["F", "G", "E", "G", "G"], dtype=pl.Categorical),
+            pl.Series("b_cat", ["F", "a", "E", "c", "G"], dtype=pl.Categorical),
         ]
     )
-    with pytest.raises(
-        pl.ComputeError,
-        match=(
-            "cannot compare categoricals originating from different sources; consider"
-            " setting a global string cache"
-        ),
-    ):
-        df_cat.filter(pl.col("a_cat") == pl.col("b_cat"))
+    # categoricals of different sources are compared on their strings
+    out = df_cat.filter(pl.col("a_cat") == pl.col("b_cat"))
+    assert out["a_cat"].to_list() == ["a", "c"]
+    out = df_cat.filter(pl.col("a_cat") != pl.col("b_cat"))
+    assert out["a_cat"].to_list() == ["c", "b", "b"]
 
 
 def test_cast_null_to_categorical() -> None:
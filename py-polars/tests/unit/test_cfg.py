@@ -465,14 +465,20 @@ def test_string_cache() -> None:
     df1 = pl.DataFrame({"a": ["foo", "bar", "ham"], "b": [1, 2, 3]})
     df2 = pl.DataFrame({"a": ["foo", "spam", "eggs"], "c": [3, 2, 2]})
 
-    # ensure cache is off when casting to categorical; the join will fail
+    # ensure cache is off when casting to categorical; the join unifies the
+    # categories of both sides
     pl.enable_string_cache(False)
     assert pl.using_string_cache() is False
 
     df1a = df1.with_columns(pl.col("a").cast(pl.Categorical))
     df2a = df2.with_columns(pl.col("a").cast(pl.Categorical))
-    with pytest.raises(pl.ComputeError):
-        _ = df1a.join(df2a, on="a", how="inner")
+    out = df1a.join(df2a, on="a", how="inner")
+    assert out.schema == {"a": pl.Categorical, "b": pl.Int64, "c": pl.Int64}
+    assert out.with_columns(pl.col("a").cast(pl.Utf8)).to_dict(False) == {
+        "a": ["foo"],
+        "b": [1],
+        "c": [3],
+    }
 
     # now turn on the cache
     pl.enable_string_cache(True)